@@ -1,10 +1,26 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::{AssociatedToken, get_associated_token_address_with_program_id},
+    associated_token::{self, AssociatedToken, get_associated_token_address_with_program_id},
     token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
     token,
-    token_2022,
+    token_2022::{
+        self,
+        spl_token_2022::{
+            extension::{
+                interest_bearing_mint::InterestBearingConfig, transfer_hook::TransferHook,
+                BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+            },
+            state::Mint as MintState,
+        },
+    },
 };
+use anchor_lang::system_program;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+#[cfg(feature = "debug")]
+use solana_program::compute_units::sol_remaining_compute_units;
 
 declare_id!("Bi1y2G3hteJwbeQk7QAW9Uk7Qq2h9bPbDYhPCKSuE2W2");
 
@@ -19,11 +35,54 @@ solana_security_txt::security_txt! {
 }
 
 // Protocol wallet for mainnet (receives 1% fee)
+#[cfg(not(feature = "devnet"))]
 pub const PROTOCOL_WALLET: Pubkey = pubkey!("2zMEvEkyQKTRjiGkwYPXjPsJUp8eR1rVjoYQ7PzVVZnP");
+// Devnet/testnet/fork builds pay a wallet the deployer controls, read at compile time:
+// CASCADEPAY_PROTOCOL_WALLET=<pubkey> anchor build -- --features devnet
+#[cfg(feature = "devnet")]
+pub const PROTOCOL_WALLET: Pubkey = Pubkey::from_str_const(env!("CASCADEPAY_PROTOCOL_WALLET"));
 pub const PROTOCOL_FEE_BPS: u16 = 100;         // 1% = 100 basis points
 pub const REQUIRED_SPLIT_TOTAL: u16 = 9900;    // Recipients MUST total 99%
-pub const MIN_RECIPIENTS: usize = 2;
+pub const REQUIRED_SPLIT_TOTAL_PPM: u32 = 990_000; // Same 99% in parts-per-million
+pub const MIN_RECIPIENT_TOTAL_BPS: u16 = 5000; // A reserve can take recipients down to 50%
+pub const BPS_DENOMINATOR: u32 = 10_000;
+pub const PPM_DENOMINATOR: u32 = 1_000_000;
+pub const MIN_RECIPIENTS: usize = 1;           // A single payee (escrow / invoice) takes the full 99%
+pub const MIN_RECIPIENTS_AFTER_RELEASE: usize = 2; // Leaving can't turn a split into a single payee
 pub const MAX_RECIPIENTS: usize = 20;
+pub const MAX_UNCLAIMED: usize = 32;           // Held-funds entries, independent of MAX_RECIPIENTS
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+pub const MAX_TIP_BPS: u16 = 500;              // Execution-time tip capped at 5%
+pub const MAX_METADATA_URI_LEN: usize = 128;   // Off-chain agreement URI (IPFS/Arweave)
+pub const MAX_EXECUTORS: usize = 5;            // Permissioned-executor allowlist bound
+pub const MAX_AUTHORITIES: usize = 7;          // M-of-N authority set bound
+pub const MAX_FEE_EXEMPT_MINTS: usize = 16;    // Admin-managed fee-exempt mint list bound
+pub const MAX_FEE_TIERS: usize = 8;            // Admin-managed volume fee tiers per mint
+pub const MAX_PROTOCOL_FEE_WALLETS: usize = 16; // Admin-managed per-mint protocol fee wallets
+pub const MAX_FEE_FLOOR_BPS: u16 = 1000;       // A protocol fee floor never takes more than 10% of a round
+pub const MAX_MULTI_CONFIGS: usize = 8;        // execute_split_multi bound (account and CU budget)
+pub const AUDIT_LOG_CAPACITY: usize = 32;      // Distributions kept in an opt-in AuditLog ring buffer
+pub const MAX_INDEXED_CONFIGS: usize = 64;     // Configs listed in an opt-in AuthorityIndex
+pub const UNCLAIMED_EXPIRY_SECONDS: i64 = 180 * 24 * 60 * 60; // Held funds sweepable after 180 days
+pub const TAKEOVER_INACTIVITY_SECONDS: i64 = 365 * 24 * 60 * 60; // Authority silence before recipient_takeover
+// Smallest balance (base units) whose 1% fee is non-zero; execute_split waits below it
+pub const MIN_SPLIT_BALANCE: u64 = (BPS_DENOMINATOR / PROTOCOL_FEE_BPS as u32) as u64;
+
+// SplitConfig layout version. Bump whenever fields are appended to the account
+// and handle any non-zero defaults (or grown Vec elements) in migrate_split_config.
+#[constant]
+pub const CURRENT_VERSION: u8 = 38;
+
+// Event layout version, carried as the first field of every event. Fields are only
+// ever appended to events; bump this when one gains a field so indexers can branch
+// on it (older decoders still read the prefix they know)
+#[constant]
+pub const EVENT_SCHEMA_VERSION: u8 = 5;
+
+// Byte offset of the recipients Vec (discriminator + version + authority + mint + vault)
+const RECIPIENTS_OFFSET: usize = 8 + 1 + 32 + 32 + 32;
+// First version with the current Recipient layout (push_paused added)
+const RECIPIENT_LAYOUT_VERSION: u8 = 30;
 
 // SplitConfig account size calculation (pre-allocated for MAX_RECIPIENTS)
 pub const SPLIT_CONFIG_SIZE: usize =
@@ -32,8 +91,114 @@ pub const SPLIT_CONFIG_SIZE: usize =
     32 +  // authority (Pubkey)
     32 +  // mint (Pubkey)
     32 +  // vault (Pubkey)
-    4 + (34 * MAX_RECIPIENTS) +  // recipients Vec (4 byte length + Recipient * max)
-    4 + (48 * MAX_RECIPIENTS) +  // unclaimed_amounts Vec (4 byte length + UnclaimedAmount * max)
+    4 + (94 * MAX_RECIPIENTS) +  // recipients Vec (4 byte length + Recipient * max) - destination v5, label v8, frozen v15, cliff_ts v19, accepted v28, push_paused v30
+    4 + (48 * MAX_UNCLAIMED) +  // unclaimed_amounts Vec (4 byte length + UnclaimedAmount * max) - 32 entries v17
+    1 +   // bump (u8)
+    8 +   // total_distributed (u64) - v2
+    8 +   // min_execute_amount (u64) - v3
+    8 +   // max_execute_amount (u64) - v3
+    1 +   // split_cursor (u8) - v4
+    8 +   // round_balance (u64) - v4
+    8 +   // round_distributed (u64) - v4
+    8 +   // round_held (u64) - v4
+    1 +   // precision (Precision) - v6
+    4 + (4 * MAX_RECIPIENTS) +  // share_ppm Vec (4 byte length + u32 * max) - v6
+    1 +   // pull_only (bool) - v7
+    1 +   // hold_protocol_fee (bool) - v7
+    4 + MAX_METADATA_URI_LEN +  // metadata_uri String (4 byte length + bytes) - v9
+    4 + (32 * MAX_EXECUTORS) +  // executor_allowlist Vec (4 byte length + pubkeys) - v10
+    8 +   // min_payout (u64) - v11
+    4 +   // recipients_epoch (u32) - v12
+    1 +   // strict_fee (bool) - v13
+    1 + 32 +  // release_authority Option<Pubkey> - v14
+    1 +   // released (bool) - v14
+    8 +   // stream_rate (u64) - v16
+    8 +   // last_stream_ts (i64) - v16
+    8 +   // execution_nonce (u64) - v18
+    1 +   // cliff_redistribute (bool) - v19
+    4 + (64 * MAX_RECIPIENTS) +  // claim_delegates Vec (4 byte length + ClaimDelegate * max) - v20
+    32 +  // reserve (Pubkey) - v21
+    2 +   // reserve_bps (u16) - v21
+    1 +   // paused (bool) - v22
+    4 + (32 * MAX_AUTHORITIES) +  // authorities Vec (4 byte length + pubkeys) - v23
+    1 +   // threshold (u8) - v23
+    8 +   // last_seen_balance (u64) - v24
+    8 +   // pending_protocol_fee (u64) - v25
+    4 +   // claim_delay_seconds (u32) - v26
+    2 +   // remainder_recipient_index (Option<u8>) - v27
+    1 +   // require_acceptance (bool) - v28
+    8 +   // last_authority_activity_ts (i64) - v29
+    4 +   // min_execute_interval_seconds (u32) - v31
+    8 +   // last_execution_ts (i64) - v31
+    1 + 32 + // referrer (Option<Pubkey>) - v32
+    1 +   // audit_log_enabled (bool) - v33
+    1 +   // claim_receipts (bool) - v34
+    8 +   // claim_receipt_nonce (u64) - v34
+    32 +  // ops_authority (Pubkey) - v35
+    8 +   // executor_tip_lamports (u64) - v36
+    8 +   // executor_reserve_lamports (u64) - v36
+    1 + 4 + (94 * MAX_RECIPIENTS) + 4 + (4 * MAX_RECIPIENTS) + 8 + // pending_recipients (Option<PendingRecipients>) - v37
+    8;    // round_fee_floor (u64) - v38
+
+// ProtocolConfig account size calculation (singleton, admin-managed)
+pub const PROTOCOL_CONFIG_SIZE: usize =
+    8 +   // discriminator
+    32 +  // admin (Pubkey)
+    8 +   // creation_fee_lamports (u64)
+    8 +   // creation_fee_tokens (u64)
+    1;    // bump (u8)
+
+// AdminConfig account size calculation (singleton, emergency controls)
+pub const ADMIN_CONFIG_SIZE: usize =
+    8 +   // discriminator
+    32 +  // admin (Pubkey)
+    1 +   // global_paused (bool)
+    1;    // bump (u8)
+
+// FeeExemptMints account size calculation (singleton, admin-managed)
+pub const FEE_EXEMPT_MINTS_SIZE: usize =
+    8 +   // discriminator
+    4 + (32 * MAX_FEE_EXEMPT_MINTS) +  // mints Vec
+    1;    // bump (u8)
+
+// ProtocolFeeWallets account size calculation (singleton, admin-managed)
+pub const PROTOCOL_FEE_WALLETS_SIZE: usize =
+    8 +   // discriminator
+    4 + (64 * MAX_PROTOCOL_FEE_WALLETS) +  // wallets Vec (4 byte length + (mint, wallet) * max)
+    1;    // bump (u8)
+
+// FeeTiers account size calculation (one per mint, admin-managed)
+pub const FEE_TIERS_SIZE: usize =
+    8 +   // discriminator
+    32 +  // mint (Pubkey)
+    4 + (10 * MAX_FEE_TIERS) +  // tiers Vec (4 byte length + FeeTier * max)
+    1 +   // bump (u8)
+    8;    // min_fee (u64)
+
+// AuditLog account size calculation (per config, opt-in)
+pub const AUDIT_LOG_SIZE: usize =
+    8 +   // discriminator
+    32 +  // config (Pubkey)
+    1 +   // head (u8)
+    4 + (24 * AUDIT_LOG_CAPACITY) +  // entries Vec (4 byte length + AuditEntry * max)
+    1;    // bump (u8)
+
+// ClaimReceipt account size calculation (one per receipted claim)
+pub const CLAIM_RECEIPT_SIZE: usize =
+    8 +   // discriminator
+    32 +  // recipient (Pubkey)
+    32 +  // config (Pubkey)
+    32 +  // mint (Pubkey)
+    8 +   // amount (u64)
+    8 +   // timestamp (i64)
+    8 +   // nonce (u64)
+    1;    // bump (u8)
+
+// AuthorityIndex account size calculation (per authority, opt-in)
+pub const AUTHORITY_INDEX_SIZE: usize =
+    8 +   // discriminator
+    32 +  // authority (Pubkey)
+    4 + (32 * MAX_INDEXED_CONFIGS) +  // configs Vec (4 byte length + Pubkey * max)
     1;    // bump (u8)
 
 #[program]
@@ -42,19 +207,44 @@ pub mod cascadepay {
 
     /// Creates a new split configuration with vault
     /// Validates recipient ATAs on-chain (defense in depth)
+    /// Optional settings (permanent-delegate opt-in, reserve, claim delay, referrer)
+    /// come in `options`; see CreateOptions
     pub fn create_split_config<'info>(
         ctx: Context<'_, '_, 'info, 'info, CreateSplitConfig<'info>>,
         mint: Pubkey,
         recipients: Vec<Recipient>,
+        precision: Precision,
+        share_ppm: Vec<u32>,
+        metadata_uri: String,
+        options: CreateOptions,
     ) -> Result<()> {
+        let CreateOptions { allow_permanent_delegate, reserve, claim_delay_seconds, referrer } = options;
         require!(
             recipients.len() >= MIN_RECIPIENTS && recipients.len() <= MAX_RECIPIENTS,
             ErrorCode::InvalidRecipientCount
         );
+        require!(metadata_uri.len() <= MAX_METADATA_URI_LEN, ErrorCode::MetadataUriTooLong);
+        require_supported_mint(&ctx.accounts.mint.to_account_info(), allow_permanent_delegate)?;
+
+        let (reserve, reserve_bps) = match reserve {
+            Some(reserve) => {
+                require!(
+                    reserve.bps > 0 && reserve.bps <= REQUIRED_SPLIT_TOTAL - MIN_RECIPIENT_TOTAL_BPS,
+                    ErrorCode::InvalidReserve
+                );
+                require!(reserve.destination != Pubkey::default(), ErrorCode::ZeroAddress);
+                (reserve.destination, reserve.bps)
+            }
+            None => (Pubkey::default(), 0),
+        };
 
-        // Validate shares sum to 9900 (99%)
-        let sum: u32 = recipients.iter().map(|r| r.percentage_bps as u32).sum();
-        require!(sum == REQUIRED_SPLIT_TOTAL as u32, ErrorCode::InvalidSplitTotal);
+        // Validate shares sum to 99% less the reserve in the chosen precision
+        // (100% less the reserve for fee-exempt mints)
+        let mut recipients = recipients;
+        let fee_exempt = ctx.accounts.fee_exempt_mints.is_exempt(&mint);
+        validate_shares(precision, &mut recipients, &share_ppm, fee_exempt, reserve_bps)?;
+        require_sorted_recipients(&recipients)?;
+        carry_acceptance(&[], &mut recipients);
 
         // Validate recipient ATAs passed via remaining_accounts
         require!(
@@ -67,15 +257,9 @@ pub mod cascadepay {
 
             // Validate recipient address is not zero
             require!(recipient.address != Pubkey::default(), ErrorCode::ZeroAddress);
-            require!(recipient.percentage_bps > 0, ErrorCode::ZeroPercentage);
 
-            // Check for duplicate recipients (prevent same address appearing twice)
-            for j in (i+1)..recipients.len() {
-                require!(
-                    recipient.address != recipients[j].address,
-                    ErrorCode::DuplicateRecipient
-                );
-            }
+            // A recipient paid into the vault itself (address = this config PDA)
+            require_not_vault(recipient_ata_info, &ctx.accounts.vault.key())?;
 
             // Validate remaining_accounts entry is read-only during creation
             require!(
@@ -83,6 +267,24 @@ pub mod cascadepay {
                 ErrorCode::RecipientATAShouldBeReadOnly
             );
 
+            // Sub-splits pass the child config; its vault is derived at execution
+            if let RecipientDestination::SubSplit(child) = recipient.destination {
+                require_child_config(
+                    recipient_ata_info,
+                    &child,
+                    &mint,
+                    &ctx.accounts.split_config.key(),
+                )?;
+                continue;
+            }
+
+            require_recipient_destination(
+                recipient_ata_info,
+                recipient,
+                &mint,
+                &ctx.accounts.token_program.key(),
+            )?;
+
             // Validate ATA exists and is valid
             require!(!recipient_ata_info.data_is_empty(), ErrorCode::RecipientATADoesNotExist);
 
@@ -94,257 +296,568 @@ pub mod cascadepay {
             let recipient_ata = InterfaceAccount::<'info, TokenAccount>::try_from(recipient_ata_info)
                 .map_err(|_| ErrorCode::RecipientATAInvalid)?;
 
-            require!(recipient_ata.owner == recipient.address, ErrorCode::RecipientATAWrongOwner);
+            require!(
+                recipient_ata.owner == destination_owner(recipient),
+                ErrorCode::RecipientATAWrongOwner
+            );
             require!(recipient_ata.mint == mint, ErrorCode::RecipientATAWrongMint);
         }
 
         let config = &mut ctx.accounts.split_config;
-        config.version = 1;  // Current version
+        config.version = CURRENT_VERSION;
         config.authority = ctx.accounts.authority.key();
         config.mint = mint;
         config.vault = ctx.accounts.vault.key();
         config.recipients = recipients.clone();
         config.unclaimed_amounts = Vec::new();
         config.bump = ctx.bumps.split_config;
+        config.total_distributed = 0;
+        config.min_execute_amount = 0;
+        config.max_execute_amount = 0;
+        config.split_cursor = 0;
+        config.round_balance = 0;
+        config.round_distributed = 0;
+        config.round_held = 0;
+        config.precision = precision;
+        config.share_ppm = share_ppm;
+        config.pull_only = false;
+        config.hold_protocol_fee = false;
+        config.metadata_uri = metadata_uri;
+        config.executor_allowlist = Vec::new();
+        config.min_payout = 0;
+        config.recipients_epoch = 0;
+        config.strict_fee = false;
+        config.release_authority = None;
+        config.released = false;
+        config.stream_rate = 0;
+        config.last_stream_ts = 0;
+        config.execution_nonce = 0;
+        config.cliff_redistribute = false;
+        config.claim_delegates = Vec::new();
+        config.reserve = reserve;
+        config.reserve_bps = reserve_bps;
+        config.paused = false;
+        config.authorities = Vec::new();
+        config.threshold = 0;
+        config.last_seen_balance = 0;
+        config.pending_protocol_fee = 0;
+        config.claim_delay_seconds = claim_delay_seconds;
+        config.remainder_recipient_index = None;
+        config.require_acceptance = false;
+        config.min_execute_interval_seconds = 0;
+        config.last_execution_ts = 0;
+        config.referrer = referrer;
+        config.audit_log_enabled = false;
+        config.claim_receipts = false;
+        config.claim_receipt_nonce = 0;
+        config.ops_authority = ctx.accounts.authority.key();
+        config.executor_tip_lamports = 0;
+        config.executor_reserve_lamports = 0;
+        config.pending_recipients = None;
+        config.round_fee_floor = 0;
+        config.last_authority_activity_ts = Clock::get()?.unix_timestamp;
 
         emit!(SplitConfigCreated {
+            schema_version: EVENT_SCHEMA_VERSION,
             config: config.key(),
             authority: config.authority,
             mint: config.mint,
             vault: config.vault,
             recipients_count: recipients.len() as u8,
+            labels: recipients.iter().map(|r| r.label).collect(),
+            metadata_uri: config.metadata_uri.clone(),
+            claim_delay_seconds,
             timestamp: Clock::get()?.unix_timestamp,
+            referrer,
+            mint_freeze_authority: ctx.accounts.mint.freeze_authority.into(),
         });
+        emit_recipients_registered(config.key(), &recipients, &config.share_ppm)?;
+        emit_explicit_destinations(config.key(), &recipients)?;
+
+        if let Some(index) = ctx.accounts.authority_index.as_mut() {
+            require!(index.configs.len() < MAX_INDEXED_CONFIGS, ErrorCode::AuthorityIndexFull);
+            index.configs.push(ctx.accounts.split_config.key());
+        }
+
+        // One-time creation fee (zero by default)
+        let fee_lamports = ctx.accounts.protocol_config.creation_fee_lamports;
+        let fee_tokens = ctx.accounts.protocol_config.creation_fee_tokens;
+
+        if fee_lamports > 0 {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.protocol_wallet.to_account_info(),
+                },
+            );
+            system_program::transfer(cpi_ctx, fee_lamports)?;
+        }
+
+        if fee_tokens > 0 {
+            let authority_token_account = ctx.accounts.authority_token_account.as_ref()
+                .ok_or(ErrorCode::MissingCreationFeeAccount)?;
+            let protocol_ata = ctx.accounts.protocol_ata.as_ref()
+                .ok_or(ErrorCode::MissingCreationFeeAccount)?;
+
+            let expected_protocol_ata = get_associated_token_address_with_program_id(
+                &PROTOCOL_WALLET,
+                &ctx.accounts.mint.key(),
+                &ctx.accounts.token_program.key(),
+            );
+            require!(
+                protocol_ata.key() == expected_protocol_ata,
+                ErrorCode::InvalidProtocolFeeRecipient
+            );
+
+            let cpi_accounts = TransferChecked {
+                from: authority_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: protocol_ata.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+            );
+            token_interface::transfer_checked(cpi_ctx, fee_tokens, ctx.accounts.mint.decimals)?;
+        }
+
+        if fee_lamports > 0 || fee_tokens > 0 {
+            emit!(ConfigCreationFeePaid {
+                schema_version: EVENT_SCHEMA_VERSION,
+                config: ctx.accounts.split_config.key(),
+                authority: ctx.accounts.authority.key(),
+                lamports: fee_lamports,
+                tokens: fee_tokens,
+                mint,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
 
         Ok(())
     }
 
-    /// Executes a payment split by draining vault
-    /// Permissionless - anyone can call
+    /// create_split_config with human percentages: `percentage_bps` holds each
+    /// recipient's share of 100.00% (summing to 10000), scaled here to the recipient
+    /// budget (9900, or 10000 on fee-exempt mints) by largest remainder
+    pub fn create_split_config_pct<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSplitConfig<'info>>,
+        mint: Pubkey,
+        recipients: Vec<Recipient>,
+        metadata_uri: String,
+        allow_permanent_delegate: bool,
+    ) -> Result<()> {
+        let fee_exempt = ctx.accounts.fee_exempt_mints.is_exempt(&mint);
+        let budget = required_share_total(Precision::Bps, fee_exempt, 0) as u16;
+        let mut recipients = recipients;
+        scale_percentages(&mut recipients, budget)?;
+
+        create_split_config(
+            ctx,
+            mint,
+            recipients,
+            Precision::Bps,
+            Vec::new(),
+            metadata_uri,
+            CreateOptions::with_permanent_delegate(allow_permanent_delegate),
+        )
+    }
+
+    /// create_split_config with relative weights ("3 parts, 2 parts, 5 parts"): `weights[i]`
+    /// is recipients[i]'s share of the weight total, apportioned to the recipient budget
+    /// (9900, or 10000 on fee-exempt mints) by largest remainder. `percentage_bps` inputs
+    /// are ignored; only the resulting bps are stored
+    pub fn create_split_config_weights<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateSplitConfig<'info>>,
+        mint: Pubkey,
+        recipients: Vec<Recipient>,
+        weights: Vec<u32>,
+        metadata_uri: String,
+        allow_permanent_delegate: bool,
+    ) -> Result<()> {
+        require!(weights.len() == recipients.len(), ErrorCode::InvalidWeights);
+        let fee_exempt = ctx.accounts.fee_exempt_mints.is_exempt(&mint);
+        let budget = required_share_total(Precision::Bps, fee_exempt, 0) as u16;
+        let mut recipients = recipients;
+        apportion_weights(&mut recipients, &weights, budget)?;
+
+        create_split_config(
+            ctx,
+            mint,
+            recipients,
+            Precision::Bps,
+            Vec::new(),
+            metadata_uri,
+            CreateOptions::with_permanent_delegate(allow_permanent_delegate),
+        )
+    }
+
+    /// Executes a payment split over the vault's fresh balance
+    /// Funds already held for unclaimed entries stay earmarked and are never re-split
+    /// Permissionless - anyone can call, unless the config has an executor allowlist
     /// Gracefully handles missing recipient ATAs (holds as unclaimed)
+    /// With `create_atas`, missing ATAs are created instead (executor pays rent)
+    /// Optional tip (up to MAX_TIP_BPS) is carved from the vault before the normal split
+    /// remaining_accounts: recipient ATAs, recipient wallets (only with `create_atas`),
+    /// tip ATA (only with a tip), reserve ATA (reserve configs only), protocol ATA (LAST,
+    /// unless passed as the named protocol_ata account)
+    /// Returns ExecuteResult via set_return_data for CPI callers (all zero on a no-op)
+    /// `expected_nonce` (opt-in) must equal config.execution_nonce, so a retried
+    /// transaction fails with StaleNonce instead of splitting again
+    /// `target_amount` (opt-in) splits exactly that much of the releasable balance
+    /// (TargetAboveBalance if it isn't there), so a deposit landing mid-batch can't change
+    /// the round; the rest stays in the vault for the next one. None splits it all
     pub fn execute_split<'info>(
         ctx: Context<'_, '_, 'info, 'info, ExecuteSplit<'info>>,
-    ) -> Result<()> {
-        let vault_balance = ctx.accounts.vault.amount;
-        if vault_balance == 0 {
-            return Ok(()); // No-op if vault empty
+        create_atas: bool,
+        tip_recipient: Option<Pubkey>,
+        tip_bps: u16,
+        expected_nonce: Option<u64>,
+        target_amount: Option<u64>,
+    ) -> Result<ExecuteResult> {
+        #[cfg(feature = "debug")]
+        let units_at_start = sol_remaining_compute_units();
+
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require_config_token_accounts(&ctx.accounts.split_config, &ctx.accounts.vault, &ctx.accounts.mint)?;
+        require_vault_not_frozen(&ctx.accounts.vault)?;
+        if let Some(expected_nonce) = expected_nonce {
+            require!(
+                expected_nonce == ctx.accounts.split_config.execution_nonce,
+                ErrorCode::StaleNonce
+            );
         }
 
-        let mut distributed = 0u64;
-        let mut held_as_unclaimed = 0u64;
+        let result = split_vault(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            create_atas,
+            tip_recipient,
+            tip_bps,
+            target_amount,
+        )?;
 
-        // Setup PDA signer (capture values before any mutations)
-        let authority = ctx.accounts.split_config.authority;
-        let mint = ctx.accounts.split_config.mint;
-        let bump = ctx.accounts.split_config.bump;
-        let config_key = ctx.accounts.split_config.key();
+        // Diagnostics builds only: lets integrators size recipient counts per transaction
+        #[cfg(feature = "debug")]
+        emit!(ExecuteComputeUnits {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            recipient_count: ctx.accounts.split_config.recipients.len() as u8,
+            units_at_start,
+            units_at_end: sol_remaining_compute_units(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-        let seeds = &[
-            b"split_config",
-            authority.as_ref(),
-            mint.as_ref(),
-            &[bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
+        Ok(result)
+    }
 
-        // Clone recipients to avoid borrow issues
-        let recipients = ctx.accounts.split_config.recipients.clone();
+    /// Authority splits the fresh balance once to an ad-hoc recipient set (e.g. a bonus
+    /// round) without touching the stored recipients. The set is validated like create
+    /// (bps, same total, sorted, 1..=MAX_RECIPIENTS); reserve, fee and execution bounds
+    /// apply as in execute_split. Any failed transfer aborts the call instead of holding
+    /// remaining_accounts: one destination per ad-hoc recipient, reserve ATA (reserve
+    /// configs only), protocol ATA (LAST, unless passed as the named protocol_ata account)
+    pub fn execute_adhoc_split<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSplit<'info>>,
+        recipients: Vec<Recipient>,
+    ) -> Result<ExecuteResult> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require_config_token_accounts(&ctx.accounts.split_config, &ctx.accounts.vault, &ctx.accounts.mint)?;
+        require_vault_not_frozen(&ctx.accounts.vault)?;
+        require_keys_eq!(
+            ctx.accounts.executor.key(),
+            ctx.accounts.split_config.authority,
+            ErrorCode::Unauthorized
+        );
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(
+            !ctx.accounts.split_config.split_in_progress(),
+            ErrorCode::ChunkedSplitInProgress
+        );
 
-        // Distribute to configured recipients
-        for (i, recipient) in recipients.iter().enumerate() {
-            let recipient_ata_info = &ctx.remaining_accounts[i];
+        require!(
+            recipients.len() >= MIN_RECIPIENTS && recipients.len() <= MAX_RECIPIENTS,
+            ErrorCode::InvalidRecipientCount
+        );
+        let mut recipients = recipients;
+        let fee_exempt = ctx.accounts.fee_exempt_mints.is_exempt(&ctx.accounts.split_config.mint);
+        let reserve_bps = ctx.accounts.split_config.reserve_bps;
+        validate_shares(Precision::Bps, &mut recipients, &[], fee_exempt, reserve_bps)?;
+        require_sorted_recipients(&recipients)?;
+        require!(
+            ctx.remaining_accounts.len()
+                == recipients.len() + reserve_slots(&ctx.accounts.split_config) + protocol_slots(ctx.accounts),
+            ErrorCode::RecipientATACountMismatch
+        );
+        require_unique_accounts(ctx.remaining_accounts)?;
 
-            // Calculate amount (floor division)
-            let amount = (vault_balance as u128)
-                .checked_mul(recipient.percentage_bps as u128)
-                .ok_or(ErrorCode::MathOverflow)?
-                .checked_div(10000u128)
-                .ok_or(ErrorCode::MathOverflow)?
-                .try_into()
-                .map_err(|_| ErrorCode::MathOverflow)?;
-
-            if amount > 0 {
-                // Attempt to send to recipient
-                match validate_and_send_to_recipient(
-                    recipient_ata_info,
-                    recipient,
-                    amount,
-                    &ctx.accounts.mint,
-                    &ctx.accounts.vault,
-                    &ctx.accounts.split_config.to_account_info(),
-                    &ctx.accounts.token_program,
-                    signer_seeds,
-                ) {
-                    Ok(()) => {
-                        distributed = distributed.checked_add(amount)
-                            .ok_or(ErrorCode::MathOverflow)?;
-                    }
-                    Err(e) => {
-                        // Hold as unclaimed - STAYS IN VAULT
-                        let split_config = &mut ctx.accounts.split_config;
-                        if let Some(existing) = split_config.unclaimed_amounts.iter_mut()
-                            .find(|u| u.recipient == recipient.address)
-                        {
-                            existing.amount = existing.amount.checked_add(amount)
-                                .ok_or(ErrorCode::MathOverflow)?;
-                            existing.timestamp = Clock::get()?.unix_timestamp;
-                        } else {
-                            // Check we don't exceed maximum unclaimed entries
-                            require!(
-                                split_config.unclaimed_amounts.len() < MAX_RECIPIENTS,
-                                ErrorCode::TooManyUnclaimedEntries
-                            );
-
-                            split_config.unclaimed_amounts.push(UnclaimedAmount {
-                                recipient: recipient.address,
-                                amount,
-                                timestamp: Clock::get()?.unix_timestamp,
-                            });
-                        }
-
-                        held_as_unclaimed = held_as_unclaimed.checked_add(amount)
-                            .ok_or(ErrorCode::MathOverflow)?;
-
-                        emit!(RecipientPaymentHeld {
-                            config: config_key,
-                            recipient: recipient.address,
-                            amount,
-                            reason: format!("{:?}", e),
-                            timestamp: Clock::get()?.unix_timestamp,
-                        });
-                    }
-                }
+        let accounts = &mut *ctx.accounts;
+        let undistributed = undistributed_balance(&accounts.split_config, accounts.vault.amount)?;
+        record_balance_increase(&mut accounts.split_config, undistributed)?;
+        let now = Clock::get()?.unix_timestamp;
+        let balance = releasable_balance(&accounts.split_config, undistributed, now);
+        if !check_execute_bounds(&accounts.split_config, balance)? {
+            return Ok(ExecuteResult::default());
+        }
+        accounts.split_config.last_stream_ts = now;
+
+        let mint = accounts.mint.key();
+        let token_program = accounts.token_program.key();
+        let mut distributed: u64 = 0;
+        for (recipient, destination_info) in recipients.iter().zip(ctx.remaining_accounts) {
+            require_recipient_destination(destination_info, recipient, &mint, &token_program)?;
+            require_not_vault(destination_info, &accounts.vault.key())?;
+            require_token_program(destination_info, &token_program)?;
+
+            let amount = share_amount(balance, recipient.percentage_bps)?;
+            if amount == 0 {
+                continue;
             }
+            validate_and_send_to_recipient(
+                destination_info,
+                recipient,
+                amount,
+                &accounts.split_config,
+                &accounts.mint,
+                &accounts.vault,
+                &accounts.token_program,
+            )
+            .map_err(HeldReason::error)?;
+            distributed = distributed.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
         }
 
-        // Protocol receives: 1% + dust only (NOT unclaimed amounts)
-        let protocol_fee = vault_balance
-            .checked_sub(distributed)
-            .ok_or(ErrorCode::MathUnderflow)?
-            .checked_sub(held_as_unclaimed)
-            .ok_or(ErrorCode::MathUnderflow)?;
+        let result = complete_split(accounts, ctx.remaining_accounts, balance, distributed, 0, 0)?;
 
-        if protocol_fee > 0 {
-            // 1. Derive expected protocol ATA (Token-2022 compatible)
-            let expected_protocol_ata = get_associated_token_address_with_program_id(
-                &PROTOCOL_WALLET,
-                &ctx.accounts.mint.key(),
-                &ctx.accounts.token_program.key()  // Uses actual token program (Token or Token-2022)
-            );
+        emit!(AdhocSplitExecuted {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: accounts.split_config.key(),
+            authority: accounts.executor.key(),
+            recipients: recipients.iter().map(|r| r.address).collect(),
+            total_amount: balance,
+            recipients_distributed: distributed,
+            protocol_fee: result.protocol_fee,
+            timestamp: now,
+        });
 
-            // 2. Get protocol ATA from remaining_accounts (should be LAST)
-            let protocol_ata_info = ctx.remaining_accounts
-                .last()
-                .ok_or(ErrorCode::MissingProtocolAccount)?;
+        Ok(result)
+    }
 
-            // 3. Validate address matches expected derivation
-            require!(
-                protocol_ata_info.key() == expected_protocol_ata,
-                ErrorCode::InvalidProtocolFeeRecipient
-            );
+    /// Runs execute_split for up to MAX_MULTI_CONFIGS configs in one transaction (keeper batching)
+    /// remaining_accounts: per config, `split_config, vault, mint, fee_tiers` followed by that
+    /// config's execute_split accounts (recipient ATAs, reserve ATA if any, protocol ATA LAST);
+    /// `group_sizes[i]` is the number of accounts after the leading four for config i
+    /// A config failing its checks (paused, version, bounds, layout, ...) is skipped with its
+    /// error code; one failing after transfers started aborts the whole transaction
+    /// Emits MultiSplitExecuted with a result per config, in order
+    pub fn execute_split_multi<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSplitMulti<'info>>,
+        group_sizes: Vec<u8>,
+    ) -> Result<()> {
+        require!(
+            !group_sizes.is_empty() && group_sizes.len() <= MAX_MULTI_CONFIGS,
+            ErrorCode::TooManyConfigs
+        );
+        let expected_accounts = group_sizes.iter().map(|&size| 4 + size as usize).sum::<usize>();
+        require!(
+            ctx.remaining_accounts.len() == expected_accounts,
+            ErrorCode::RecipientATACountMismatch
+        );
+
+        let mut results: Vec<MultiSplitResult> = Vec::with_capacity(group_sizes.len());
+        let mut offset = 0;
+        for size in group_sizes {
+            let group = &ctx.remaining_accounts[offset..offset + 4 + size as usize];
+            offset += group.len();
 
-            // 4. Validate account is writable
+            let config_key = group[0].key();
             require!(
-                protocol_ata_info.is_writable,
-                ErrorCode::InvalidProtocolFeeRecipient
+                results.iter().all(|r| r.config != config_key),
+                ErrorCode::DuplicateAccount
             );
 
-            // 5. If protocol ATA doesn't exist, skip protocol fee (graceful degradation)
-            if protocol_ata_info.data_is_empty() {
-                // Protocol ATA doesn't exist yet - protocol fee stays in vault
-                // Protocol can create ATA later and re-execute split to claim fees
-                msg!("Protocol ATA doesn't exist, skipping protocol fee transfer");
-            } else {
-                // 6. Validate account is owned by token program (SPL Token or Token-2022)
-                let valid_owner = protocol_ata_info.owner == &token::ID
-                    || protocol_ata_info.owner == &token_2022::ID;
-                require!(valid_owner, ErrorCode::InvalidProtocolFeeRecipient);
+            let checked = load_multi_group(ctx.accounts, &group[0], &group[1], &group[2], &group[3])
+                .and_then(|accounts| check_multi_group(&accounts, &group[4..]).map(|_| accounts));
+            let result = match checked {
+                Ok(mut accounts) => {
+                    let executed = split_vault(&mut accounts, &group[4..], false, None, 0, None)?;
+                    accounts.exit(&crate::ID)?;
+                    MultiSplitResult {
+                        config: config_key,
+                        distributed: executed.distributed,
+                        protocol_fee: executed.protocol_fee,
+                        held: executed.held,
+                        error_code: None,
+                    }
+                }
+                Err(error) => MultiSplitResult {
+                    config: config_key,
+                    distributed: 0,
+                    protocol_fee: 0,
+                    held: 0,
+                    error_code: Some(error_code_of(&error)),
+                },
+            };
+            results.push(result);
+        }
 
-                // 7. Deserialize and validate token account fields
-                let protocol_ata = InterfaceAccount::<'info, TokenAccount>::try_from(protocol_ata_info)
-                    .map_err(|_| ErrorCode::InvalidProtocolFeeRecipient)?;
+        emit!(MultiSplitExecuted {
+            schema_version: EVENT_SCHEMA_VERSION,
+            executor: ctx.accounts.executor.key(),
+            results,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-                require!(
-                    protocol_ata.owner == PROTOCOL_WALLET,
-                    ErrorCode::InvalidProtocolFeeRecipient
-                );
-                require!(
-                    protocol_ata.mint == ctx.accounts.mint.key(),
-                    ErrorCode::InvalidProtocolFeeRecipient
-                );
+        Ok(())
+    }
 
-                // 8. Transfer protocol fee
-                let cpi_accounts = TransferChecked {
-                    from: ctx.accounts.vault.to_account_info(),
-                    mint: ctx.accounts.mint.to_account_info(),
-                    to: protocol_ata.to_account_info(),
-                    authority: ctx.accounts.split_config.to_account_info(),
-                };
-                let cpi_ctx = CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    cpi_accounts,
-                    signer_seeds,
-                );
-                token_interface::transfer_checked(cpi_ctx, protocol_fee, ctx.accounts.mint.decimals)?;
+    /// Distributes to recipients in [start, end) only, for configs too large for one transaction
+    /// Ranges must be executed in order from 0; the first range snapshots the fresh vault balance
+    /// remaining_accounts: recipient ATAs for the range, in recipient order
+    pub fn execute_split_range<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSplit<'info>>,
+        start: u8,
+        end: u8,
+    ) -> Result<()> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require_config_token_accounts(&ctx.accounts.split_config, &ctx.accounts.vault, &ctx.accounts.mint)?;
+        require_vault_not_frozen(&ctx.accounts.vault)?;
+        if ctx.accounts.split_config.split_cursor == 0 {
+            let undistributed = undistributed_balance(&ctx.accounts.split_config, ctx.accounts.vault.amount)?;
+            promote_pending_recipients(&mut ctx.accounts.split_config, undistributed, Clock::get()?.unix_timestamp)?;
+        }
+        let config = &ctx.accounts.split_config;
+        require!(
+            start < end && end as usize <= config.recipients.len(),
+            ErrorCode::InvalidSplitRange
+        );
+        // Cursor only moves forward, so a range can't be paid twice
+        require!(start == config.split_cursor, ErrorCode::InvalidSplitRange);
+        require!(
+            ctx.remaining_accounts.len() == (end - start) as usize,
+            ErrorCode::RecipientATACountMismatch
+        );
+        require_unique_accounts(ctx.remaining_accounts)?;
+
+        if start == 0 {
+            let undistributed = undistributed_balance(config, ctx.accounts.vault.amount)?;
+            record_balance_increase(&mut ctx.accounts.split_config, undistributed)?;
+
+            let now = Clock::get()?.unix_timestamp;
+            let vault_balance = releasable_balance(&ctx.accounts.split_config, undistributed, now);
+            if !check_execute_bounds(&ctx.accounts.split_config, vault_balance)? {
+                return Ok(());
             }
+            // The fee floor comes off the top of the snapshot; finalize_split adds it to the fee
+            let fee_floor = fee_floor_top_up(ctx.accounts, vault_balance)?;
+
+            let config = &mut ctx.accounts.split_config;
+            config.last_stream_ts = now;
+            config.round_balance = vault_balance - fee_floor;
+            config.round_fee_floor = fee_floor;
+            config.round_distributed = 0;
+            config.round_held = 0;
         }
 
-        emit!(SplitExecuted {
-            config: config_key,
-            vault: ctx.accounts.vault.key(),
-            total_amount: vault_balance,
-            recipients_distributed: distributed,
-            protocol_fee,
-            held_count: held_as_unclaimed,
-            executor: ctx.accounts.executor.key(),
+        let round_balance = ctx.accounts.split_config.round_balance;
+        let (distributed, held_as_unclaimed) = distribute_to_recipients(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            None,
+            start as usize..end as usize,
+            round_balance,
+        )?;
+
+        let config = &mut ctx.accounts.split_config;
+        config.split_cursor = end;
+        config.round_distributed = config.round_distributed
+            .checked_add(distributed)
+            .ok_or(ErrorCode::MathOverflow)?;
+        config.round_held = config.round_held
+            .checked_add(held_as_unclaimed)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(SplitRangeExecuted {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            start,
+            end,
+            round_balance,
+            distributed,
+            held_amount: held_as_unclaimed,
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
-    /// Recipients claim their unclaimed funds
-    pub fn claim_unclaimed(ctx: Context<ClaimUnclaimed>) -> Result<()> {
-        let claimer = ctx.accounts.recipient.key();
-        let config_key = ctx.accounts.split_config.key();
+    /// Completes a chunked split once every recipient range has executed
+    /// Pays the reserve and protocol fee for the round and resets the cursor
+    /// remaining_accounts: reserve ATA (reserve configs only), protocol ATA (LAST, unless
+    /// passed as the named protocol_ata account)
+    /// Returns the round's ExecuteResult via set_return_data
+    pub fn finalize_split<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSplit<'info>>,
+    ) -> Result<ExecuteResult> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require_config_token_accounts(&ctx.accounts.split_config, &ctx.accounts.vault, &ctx.accounts.mint)?;
+        require_vault_not_frozen(&ctx.accounts.vault)?;
+        let protocol_slots = protocol_slots(ctx.accounts);
+        let config = &mut ctx.accounts.split_config;
+        require!(
+            config.split_in_progress() && config.split_cursor as usize == config.recipients.len(),
+            ErrorCode::SplitRangeIncomplete
+        );
+        require!(
+            ctx.remaining_accounts.len() == reserve_slots(config) + protocol_slots,
+            ErrorCode::MissingProtocolAccount
+        );
 
-        // Capture seeds values before any mutations
-        let authority = ctx.accounts.split_config.authority;
-        let mint = ctx.accounts.split_config.mint;
-        let bump = ctx.accounts.split_config.bump;
+        let round_balance = config.round_balance;
+        let distributed = config.round_distributed;
+        let held_as_unclaimed = config.round_held;
+        let fee_floor = config.round_fee_floor;
 
-        // Find and remove unclaimed entry
-        let split_config = &mut ctx.accounts.split_config;
-        let index = split_config.unclaimed_amounts.iter()
-            .position(|u| u.recipient == claimer)
-            .ok_or(ErrorCode::NothingToClaim)?;
+        config.split_cursor = 0;
+        config.round_balance = 0;
+        config.round_distributed = 0;
+        config.round_held = 0;
+        config.round_fee_floor = 0;
 
-        let unclaimed = split_config.unclaimed_amounts.remove(index);
+        complete_split(
+            ctx.accounts,
+            ctx.remaining_accounts,
+            round_balance,
+            distributed,
+            held_as_unclaimed,
+            fee_floor,
+        )
+    }
+
+    /// Recipients claim their unclaimed funds
+    /// Writes a ClaimReceipt when claim_receipt is passed (required once the config
+    /// enabled claim_receipts); the recipient pays its rent
+    pub fn claim_unclaimed(ctx: Context<ClaimUnclaimed>) -> Result<()> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require_config_token_accounts(&ctx.accounts.split_config, &ctx.accounts.vault, &ctx.accounts.mint)?;
+        let claimer = ctx.accounts.recipient.key();
+        let unclaimed = take_unclaimed(&mut ctx.accounts.split_config, claimer)?;
 
         // Transfer from vault to recipient
-        let seeds = &[
-            b"split_config",
-            authority.as_ref(),
-            mint.as_ref(),
-            &[bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.recipient_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            unclaimed.amount,
+        )?;
 
-        let cpi_accounts = TransferChecked {
-            from: ctx.accounts.vault.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            to: ctx.accounts.recipient_ata.to_account_info(),
-            authority: ctx.accounts.split_config.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            cpi_accounts,
-            signer_seeds,
-        );
-        token_interface::transfer_checked(cpi_ctx, unclaimed.amount, ctx.accounts.mint.decimals)?;
+        write_claim_receipt(ctx.accounts, ctx.bumps.claim_receipt, unclaimed.amount)?;
 
         emit!(UnclaimedFundsClaimed {
-            config: config_key,
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
             recipient: claimer,
             amount: unclaimed.amount,
             timestamp: Clock::get()?.unix_timestamp,
@@ -353,281 +866,6709 @@ pub mod cascadepay {
         Ok(())
     }
 
-    /// Updates split configuration
-    /// Only callable by authority, requires vault empty
-    pub fn update_split_config<'info>(
-        ctx: Context<'_, '_, 'info, 'info, UpdateSplitConfig<'info>>,
-        new_recipients: Vec<Recipient>,
+    /// Batched claim_unclaimed for a coordinated payout: each recipient signs for their own
+    /// held entry, so nobody can claim on another's behalf
+    /// remaining_accounts: (recipient signer, recipient ATA) per claimer
+    /// Emits UnclaimedFundsClaimed per recipient
+    pub fn claim_unclaimed_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimUnclaimedMany<'info>>,
     ) -> Result<()> {
-        let config = &mut ctx.accounts.split_config;
-        let old_recipients_count = config.recipients.len() as u8;
-
-        // Require vault empty
-        require!(ctx.accounts.vault.amount == 0, ErrorCode::VaultNotEmpty);
-
-        // Validate new recipients
-        require!(
-            new_recipients.len() >= MIN_RECIPIENTS && new_recipients.len() <= MAX_RECIPIENTS,
-            ErrorCode::InvalidRecipientCount
-        );
-
-        let sum: u32 = new_recipients.iter().map(|r| r.percentage_bps as u32).sum();
-        require!(sum == REQUIRED_SPLIT_TOTAL as u32, ErrorCode::InvalidSplitTotal);
-
-        // Validate new recipient ATAs
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require!(!ctx.accounts.split_config.claim_receipts, ErrorCode::ClaimReceiptMissing);
+        require_config_token_accounts(&ctx.accounts.split_config, &ctx.accounts.vault, &ctx.accounts.mint)?;
         require!(
-            ctx.remaining_accounts.len() == new_recipients.len(),
+            !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len().is_multiple_of(2),
             ErrorCode::RecipientATACountMismatch
         );
+        require_unique_accounts(ctx.remaining_accounts)?;
 
-        for (i, recipient) in new_recipients.iter().enumerate() {
-            let recipient_ata_info = &ctx.remaining_accounts[i];
+        let mint = ctx.accounts.mint.key();
+        let token_program = ctx.accounts.token_program.key();
+        let now = Clock::get()?.unix_timestamp;
 
-            require!(!recipient_ata_info.data_is_empty(), ErrorCode::RecipientATADoesNotExist);
+        for pair in ctx.remaining_accounts.chunks(2) {
+            let (recipient_info, ata_info) = (&pair[0], &pair[1]);
+            let claimer = recipient_info.key();
+            require!(recipient_info.is_signer, ErrorCode::Unauthorized);
+            require!(
+                ata_info.key() == get_associated_token_address_with_program_id(&claimer, &mint, &token_program),
+                ErrorCode::RecipientATAInvalid
+            );
 
-            let recipient_ata = InterfaceAccount::<'info, TokenAccount>::try_from(recipient_ata_info)
-                .map_err(|_| ErrorCode::RecipientATAInvalid)?;
+            let config = &ctx.accounts.split_config;
+            require!(!config.is_frozen(&claimer), ErrorCode::RecipientIsFrozen);
+            require!(!config.before_cliff(&claimer, now), ErrorCode::RecipientBeforeCliff);
+            require!(!config.awaiting_acceptance(&claimer), ErrorCode::RecipientNotAccepted);
+            let unclaimed = take_unclaimed(&mut ctx.accounts.split_config, claimer)?;
 
-            require!(recipient_ata.owner == recipient.address, ErrorCode::RecipientATAWrongOwner);
-            require!(recipient_ata.mint == config.mint, ErrorCode::RecipientATAWrongMint);
+            transfer_from_vault(
+                &ctx.accounts.split_config,
+                &ctx.accounts.vault,
+                &ctx.accounts.mint,
+                ata_info.clone(),
+                &ctx.accounts.token_program,
+                unclaimed.amount,
+            )?;
+
+            emit!(UnclaimedFundsClaimed {
+                schema_version: EVENT_SCHEMA_VERSION,
+                config: ctx.accounts.split_config.key(),
+                recipient: claimer,
+                amount: unclaimed.amount,
+                timestamp: now,
+            });
         }
 
-        config.recipients = new_recipients.clone();
+        Ok(())
+    }
 
-        emit!(SplitConfigUpdated {
-            config: config.key(),
-            authority: config.authority,
-            old_recipients_count,
-            new_recipients_count: new_recipients.len() as u8,
+    /// Claims the signer's held entry and swaps it into another mint in one instruction
+    /// The held amount lands in recipient_ata, then `swap_program` is invoked with
+    /// `swap_data` and remaining_accounts (the recipient's route, signed only by the
+    /// recipient - never the vault PDA). recipient_output_ata must gain at least `min_out`,
+    /// otherwise the whole claim reverts and the funds stay held
+    pub fn claim_unclaimed_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimUnclaimedSwap<'info>>,
+        swap_data: Vec<u8>,
+        min_out: u64,
+    ) -> Result<()> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require!(!ctx.accounts.split_config.claim_receipts, ErrorCode::ClaimReceiptMissing);
+        require_config_token_accounts(&ctx.accounts.split_config, &ctx.accounts.vault, &ctx.accounts.mint)?;
+        require!(
+            ctx.accounts.swap_program.executable && ctx.accounts.swap_program.key() != crate::ID,
+            ErrorCode::InvalidSwapProgram
+        );
+        let claimer = ctx.accounts.recipient.key();
+        let unclaimed = take_unclaimed(&mut ctx.accounts.split_config, claimer)?;
+
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.recipient_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            unclaimed.amount,
+        )?;
+
+        let output_before = ctx.accounts.recipient_output_ata.amount;
+        let swap_ix = Instruction {
+            program_id: ctx.accounts.swap_program.key(),
+            accounts: ctx.remaining_accounts.iter()
+                .map(|account| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data: swap_data,
+        };
+        let mut swap_accounts = ctx.remaining_accounts.to_vec();
+        swap_accounts.push(ctx.accounts.swap_program.to_account_info());
+        invoke(&swap_ix, &swap_accounts)?;
+
+        ctx.accounts.recipient_output_ata.reload()?;
+        let amount_out = ctx.accounts.recipient_output_ata.amount
+            .checked_sub(output_before)
+            .ok_or(ErrorCode::SlippageExceeded)?;
+        require!(amount_out >= min_out, ErrorCode::SlippageExceeded);
+
+        emit!(UnclaimedFundsSwapped {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            recipient: claimer,
+            input_mint: ctx.accounts.mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            amount_in: unclaimed.amount,
+            amount_out,
+            swap_program: ctx.accounts.swap_program.key(),
             timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
 
-    // Note: close_split_config temporarily removed due to Bumps trait complexity
-    // Can be added back in future iteration
-}
+    /// Claims every unclaimed entry belonging to the signer in a single transfer
+    pub fn claim_all_unclaimed(ctx: Context<ClaimUnclaimed>) -> Result<()> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        let claimer = ctx.accounts.recipient.key();
+        let amount = take_all_unclaimed(&mut ctx.accounts.split_config, claimer)?;
 
-/// Helper function to validate recipient ATA and send tokens
-/// Enhanced validation to provide better error messages for debugging
-fn validate_and_send_to_recipient<'info>(
-    recipient_ata_info: &'info AccountInfo<'info>,
-    recipient: &Recipient,
-    amount: u64,
-    mint: &InterfaceAccount<'info, Mint>,
-    vault: &InterfaceAccount<'info, TokenAccount>,
-    split_config_info: &AccountInfo<'info>,
-    token_program: &Interface<'info, TokenInterface>,
-    signer_seeds: &[&[&[u8]]],
-) -> Result<()> {
-    // Validate account exists and has data
-    require!(!recipient_ata_info.data_is_empty(), ErrorCode::RecipientATADoesNotExist);
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.recipient_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            amount,
+        )?;
 
-    // Validate account is owned by token program (SPL Token or Token-2022)
-    let valid_owner = recipient_ata_info.owner == &token::ID
-        || recipient_ata_info.owner == &token_2022::ID;
-    require!(valid_owner, ErrorCode::RecipientATAInvalidOwner);
+        write_claim_receipt(ctx.accounts, ctx.bumps.claim_receipt, amount)?;
 
-    // Try to deserialize as token account
-    let recipient_ata = InterfaceAccount::<'info, TokenAccount>::try_from(recipient_ata_info)
-        .map_err(|_| ErrorCode::RecipientATAInvalid)?;
+        emit!(UnclaimedFundsClaimed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            recipient: claimer,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-    // Verify owner and mint match expected values
-    require!(recipient_ata.owner == recipient.address, ErrorCode::RecipientATAWrongOwner);
-    require!(recipient_ata.mint == mint.key(), ErrorCode::RecipientATAWrongMint);
+        Ok(())
+    }
 
-    // Transfer tokens
-    let cpi_accounts = TransferChecked {
-        from: vault.to_account_info(),
-        mint: mint.to_account_info(),
-        to: recipient_ata.to_account_info(),
-        authority: split_config_info.clone(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(
-        token_program.to_account_info(),
-        cpi_accounts,
-        signer_seeds,
-    );
-    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)?;
+    /// Claims part of the signer's oldest unclaimed entry, leaving the rest held
+    /// The entry keeps its original timestamp so expiry still counts from the hold
+    pub fn claim_partial(ctx: Context<ClaimUnclaimed>, amount: u64) -> Result<()> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        let claimer = ctx.accounts.recipient.key();
+        take_partial_unclaimed(&mut ctx.accounts.split_config, claimer, amount)?;
 
-    Ok(())
-}
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.recipient_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            amount,
+        )?;
 
-// Account Structs
+        write_claim_receipt(ctx.accounts, ctx.bumps.claim_receipt, amount)?;
+
+        emit!(UnclaimedFundsClaimed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            recipient: claimer,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Claims unclaimed wSOL and unwraps it to native SOL in one step
+    /// Closes the recipient's wSOL ATA so its lamports land in the recipient wallet
+    pub fn claim_unclaimed_native(ctx: Context<ClaimUnclaimedNative>) -> Result<()> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require!(!ctx.accounts.split_config.claim_receipts, ErrorCode::ClaimReceiptMissing);
+        let claimer = ctx.accounts.recipient.key();
+        let unclaimed = take_unclaimed(&mut ctx.accounts.split_config, claimer)?;
+
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.recipient_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            unclaimed.amount,
+        )?;
+
+        // Closing a native account releases its wrapped balance plus rent
+        let cpi_accounts = token_interface::CloseAccount {
+            account: ctx.accounts.recipient_ata.to_account_info(),
+            destination: ctx.accounts.recipient.to_account_info(),
+            authority: ctx.accounts.recipient.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+        );
+        token_interface::close_account(cpi_ctx)?;
+
+        emit!(UnclaimedFundsClaimed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            recipient: claimer,
+            amount: unclaimed.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Recipient registers (or with None, clears) a delegate allowed to trigger their claims
+    /// Claimed funds still go only to the recipient's ATA
+    pub fn set_claim_delegate(ctx: Context<SetClaimDelegate>, delegate: Option<Pubkey>) -> Result<()> {
+        let config = &mut ctx.accounts.split_config;
+        let recipient = ctx.accounts.recipient.key();
+        config.claim_delegates.retain(|d| d.recipient != recipient);
+
+        // Clearing stays open to removed recipients so stale entries can always be dropped
+        if let Some(delegate) = delegate {
+            require!(
+                config.recipients.iter().any(|r| r.address == recipient),
+                ErrorCode::NotARecipient
+            );
+            require!(
+                config.claim_delegates.len() < MAX_RECIPIENTS,
+                ErrorCode::TooManyClaimDelegates
+            );
+            config.claim_delegates.push(ClaimDelegate { recipient, delegate });
+        }
+
+        emit!(ClaimDelegateUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            recipient,
+            delegate,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Registered delegate claims a recipient's unclaimed entry into the recipient's ATA
+    /// Lets custodial platforms sweep for users without holding their keys
+    pub fn claim_unclaimed_delegated(ctx: Context<ClaimUnclaimedDelegated>) -> Result<()> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require!(!ctx.accounts.split_config.claim_receipts, ErrorCode::ClaimReceiptMissing);
+        let recipient = ctx.accounts.recipient.key();
+        let unclaimed = take_unclaimed(&mut ctx.accounts.split_config, recipient)?;
+
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.recipient_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            unclaimed.amount,
+        )?;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        emit!(UnclaimedFundsClaimed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            recipient,
+            amount: unclaimed.amount,
+            timestamp,
+        });
+        emit!(DelegatedClaimExecuted {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            recipient,
+            delegate: ctx.accounts.delegate.key(),
+            amount: unclaimed.amount,
+            timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Permissionless claim of a PDA recipient's held entry into its ATA
+    /// A PDA can only sign through its owning program's invoke_signed; this path lets any
+    /// keeper sweep for program-owned recipients instead. The caller proves the recipient
+    /// is off-curve with the same `seeds` (bump included) and `owner_program` its program
+    /// signs with. Funds can only reach the PDA's canonical ATA, where execute_split pays
+    pub fn claim_unclaimed_pda(
+        ctx: Context<ClaimUnclaimedPda>,
+        owner_program: Pubkey,
+        seeds: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        require_canonical_config(&ctx.accounts.split_config)?;
+        require!(!ctx.accounts.split_config.claim_receipts, ErrorCode::ClaimReceiptMissing);
+        let recipient = ctx.accounts.recipient.key();
+        let seed_refs: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        let derived = Pubkey::create_program_address(&seed_refs, &owner_program)
+            .map_err(|_| error!(ErrorCode::RecipientNotPda))?;
+        require_keys_eq!(derived, recipient, ErrorCode::RecipientNotPda);
+        let unclaimed = take_unclaimed(&mut ctx.accounts.split_config, recipient)?;
+
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.recipient_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            unclaimed.amount,
+        )?;
+
+        emit!(UnclaimedFundsClaimed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            recipient,
+            amount: unclaimed.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Updates split configuration
+    /// Only callable by authority, requires no undistributed balance (held entries may remain)
+    /// Removing a recipient that still has held funds emits OrphanedUnclaimed and needs
+    /// `acknowledge_orphaned`
+    /// Multisig configs: co-signers follow the recipient ATAs in remaining_accounts
+    pub fn update_split_config<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateSplitConfig<'info>>,
+        new_recipients: Vec<Recipient>,
+        share_ppm: Vec<u32>,
+        acknowledge_orphaned: bool,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(
+            ctx.remaining_accounts.len() >= new_recipients.len(),
+            ErrorCode::RecipientATACountMismatch
+        );
+        require_multisig_approval(
+            &ctx.accounts.split_config,
+            &ctx.accounts.authority.key(),
+            &ctx.remaining_accounts[new_recipients.len()..],
+            SensitiveOp::UpdateRecipients,
+        )?;
+
+        let config = &mut ctx.accounts.split_config;
+        let old_recipients_count = config.recipients.len() as u8;
+
+        // Require nothing left to split - funds are always paid to the set they were
+        // deposited under. Held entries already belong to their recipients and stay claimable
+        require!(
+            undistributed_balance(config, ctx.accounts.vault.amount)? == 0,
+            ErrorCode::VaultNotEmpty
+        );
+        require!(!config.split_in_progress(), ErrorCode::ChunkedSplitInProgress);
+
+        // Validate new recipients
+        require!(
+            new_recipients.len() >= MIN_RECIPIENTS && new_recipients.len() <= MAX_RECIPIENTS,
+            ErrorCode::InvalidRecipientCount
+        );
+
+        // Precision is fixed at creation
+        let mut new_recipients = new_recipients;
+        let fee_exempt = ctx.accounts.fee_exempt_mints.is_exempt(&config.mint);
+        validate_shares(config.precision, &mut new_recipients, &share_ppm, fee_exempt, config.reserve_bps)?;
+        require_sorted_recipients(&new_recipients)?;
+        carry_acceptance(&config.recipients, &mut new_recipients);
+
+        // Validate new recipient ATAs (count checked above, before any co-signers)
+        require_new_destinations(ctx.remaining_accounts, &new_recipients, config, &ctx.accounts.vault)?;
+
+        // Removed recipients keep their held entries (claimable by address only), but the
+        // authority must acknowledge that they drop out of the new recipient list
+        let orphaned = orphaned_unclaimed(config, &new_recipients)?;
+        require!(
+            orphaned.is_empty() || acknowledge_orphaned,
+            ErrorCode::UnacknowledgedOrphanedFunds
+        );
+        for (recipient, amount) in orphaned {
+            emit!(OrphanedUnclaimed {
+                schema_version: EVENT_SCHEMA_VERSION,
+                config: config.key(),
+                recipient,
+                amount,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        config.recipients = new_recipients.clone();
+        config.share_ppm = share_ppm;
+        // Indices shift with a new set; the remainder recipient must be chosen again
+        config.remainder_recipient_index = None;
+        config.recipients_epoch = config.recipients_epoch
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(SplitConfigUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            old_recipients_count,
+            new_recipients_count: new_recipients.len() as u8,
+            labels: new_recipients.iter().map(|r| r.label).collect(),
+            recipients_epoch: config.recipients_epoch,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        emit_recipients_registered(config.key(), &new_recipients, &config.share_ppm)?;
+        emit_explicit_destinations(config.key(), &new_recipients)?;
+
+        Ok(())
+    }
+
+    /// Schedules a recipient set to replace the current one at `effective_ts`: the first
+    /// execution at or after it promotes the set before distributing. Validated now like
+    /// update_split_config, but the vault needn't be empty; replaces any set already queued
+    /// Removed recipients keep their held entries (claimable by address only)
+    /// remaining_accounts: one destination per new recipient, then multisig co-signers
+    pub fn queue_recipients<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateSplitConfig<'info>>,
+        recipients: Vec<Recipient>,
+        share_ppm: Vec<u32>,
+        effective_ts: i64,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(
+            ctx.remaining_accounts.len() >= recipients.len(),
+            ErrorCode::RecipientATACountMismatch
+        );
+        require_multisig_approval(
+            &ctx.accounts.split_config,
+            &ctx.accounts.authority.key(),
+            &ctx.remaining_accounts[recipients.len()..],
+            SensitiveOp::UpdateRecipients,
+        )?;
+        let now = Clock::get()?.unix_timestamp;
+        require!(effective_ts > now, ErrorCode::InvalidEffectiveTime);
+
+        require!(
+            recipients.len() >= MIN_RECIPIENTS && recipients.len() <= MAX_RECIPIENTS,
+            ErrorCode::InvalidRecipientCount
+        );
+        let config = &mut ctx.accounts.split_config;
+        let mut recipients = recipients;
+        let fee_exempt = ctx.accounts.fee_exempt_mints.is_exempt(&config.mint);
+        validate_shares(config.precision, &mut recipients, &share_ppm, fee_exempt, config.reserve_bps)?;
+        require_sorted_recipients(&recipients)?;
+        require_new_destinations(ctx.remaining_accounts, &recipients, config, &ctx.accounts.vault)?;
+
+        emit!(RecipientsQueued {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            recipients_count: recipients.len() as u8,
+            labels: recipients.iter().map(|r| r.label).collect(),
+            effective_ts,
+            timestamp: now,
+        });
+        config.pending_recipients = Some(PendingRecipients {
+            recipients,
+            share_ppm,
+            effective_ts,
+        });
+
+        Ok(())
+    }
+
+    /// Drops a queued recipient set before it takes effect
+    pub fn cancel_pending_recipients(ctx: Context<UpdateSplitSettings>) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        let pending = config.pending_recipients.take()
+            .ok_or(ErrorCode::NoPendingRecipients)?;
+
+        emit!(PendingRecipientsCancelled {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            effective_ts: pending.effective_ts,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Moves share between two existing recipients without resubmitting the whole set
+    /// `rebalance_target` absorbs the difference so the total stays at 9900 bps
+    /// Requires nothing left to split, like update_split_config (Bps precision only)
+    /// Neither share may drop to 0; the remainder recipient is cleared
+    /// Multisig configs: co-signers in remaining_accounts
+    pub fn set_recipient_share(
+        ctx: Context<UpdateSplitConfig>,
+        address: Pubkey,
+        new_bps: u16,
+        rebalance_target: Pubkey,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require_multisig_approval(
+            &ctx.accounts.split_config,
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            SensitiveOp::UpdateRecipients,
+        )?;
+        let config = &mut ctx.accounts.split_config;
+        // Held entries stay claimable; only funds not yet split block a share change
+        require!(
+            undistributed_balance(config, ctx.accounts.vault.amount)? == 0,
+            ErrorCode::VaultNotEmpty
+        );
+        require!(!config.split_in_progress(), ErrorCode::ChunkedSplitInProgress);
+        require!(config.precision == Precision::Bps, ErrorCode::UnsupportedPrecision);
+        require!(address != rebalance_target, ErrorCode::DuplicateRecipient);
+        require!(new_bps > 0, ErrorCode::ZeroPercentage);
+
+        let index = config.recipients.iter()
+            .position(|r| r.address == address)
+            .ok_or(ErrorCode::NotARecipient)?;
+        let target_index = config.recipients.iter()
+            .position(|r| r.address == rebalance_target)
+            .ok_or(ErrorCode::NotARecipient)?;
+
+        let old_bps = config.recipients[index].percentage_bps;
+        let pair_total = old_bps
+            .checked_add(config.recipients[target_index].percentage_bps)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let target_bps = pair_total
+            .checked_sub(new_bps)
+            .ok_or(ErrorCode::InvalidSplitTotal)?;
+        require!(target_bps > 0, ErrorCode::ZeroPercentage);
+
+        config.recipients[index].percentage_bps = new_bps;
+        config.recipients[target_index].percentage_bps = target_bps;
+        // Shares changed under the remainder recipient; it must be chosen again
+        config.remainder_recipient_index = None;
+        config.recipients_epoch = config.recipients_epoch
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(RecipientShareUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            recipient: address,
+            old_bps,
+            new_bps,
+            rebalance_target,
+            target_bps,
+            recipients_epoch: config.recipients_epoch,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Funds the vault on behalf of the recipient set identified by `recipients_epoch`
+    /// Fails if recipients changed since the depositor read the config, so escrowed
+    /// funds are only ever split among the set they were deposited for
+    pub fn deposit(ctx: Context<Deposit>, amount: u64, recipients_epoch: u32) -> Result<()> {
+        let config = &ctx.accounts.split_config;
+        require!(
+            recipients_epoch == config.recipients_epoch,
+            ErrorCode::StaleRecipientsForFunds
+        );
+
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.depositor_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.depositor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+        );
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        emit!(Deposited {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            depositor: ctx.accounts.depositor.key(),
+            amount,
+            recipients_epoch,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Canonical top-up: moves `amount` from the funder's token account into the vault
+    /// with transfer_checked against the config mint, so deposits are attributable on-chain.
+    /// Unlike `deposit`, not pinned to a recipient set
+    pub fn fund_vault(ctx: Context<FundVault>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidFundAmount);
+
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.funder_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.funder.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+        );
+        token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+        emit!(VaultFunded {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            amount,
+            funder: ctx.accounts.funder.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Migration utility: carries owed balances from another splitter (or an older
+    /// deployment) into a fresh config as held entries. The authority deposits their
+    /// total in the same instruction, and every held entry must stay backed by the vault
+    /// Only before the config's first execution
+    pub fn seed_unclaimed(ctx: Context<SeedUnclaimed>, entries: Vec<UnclaimedSeed>) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(
+            ctx.accounts.split_config.execution_nonce == 0
+                && ctx.accounts.split_config.total_distributed == 0,
+            ErrorCode::ConfigNotFresh
+        );
+        require!(!entries.is_empty(), ErrorCode::InvalidFundAmount);
+
+        let mut total = 0u64;
+        for entry in &entries {
+            require!(entry.amount > 0, ErrorCode::InvalidFundAmount);
+            require!(entry.recipient != Pubkey::default(), ErrorCode::ZeroAddress);
+            total = total.checked_add(entry.amount).ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.authority_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+        );
+        token_interface::transfer_checked(cpi_ctx, total, ctx.accounts.mint.decimals)?;
+
+        let config = &mut ctx.accounts.split_config;
+        let timestamp = Clock::get()?.unix_timestamp;
+        for entry in &entries {
+            hold_as_unclaimed(config, entry.recipient, entry.amount)?;
+            emit!(UnclaimedSeeded {
+                schema_version: EVENT_SCHEMA_VERSION,
+                config: config.key(),
+                recipient: entry.recipient,
+                amount: entry.amount,
+                timestamp,
+            });
+        }
+
+        // Transfer-fee mints deliver less than was sent; every entry must still be covered
+        ctx.accounts.vault.reload()?;
+        let config = &ctx.accounts.split_config;
+        let mut held = config.pending_protocol_fee;
+        for entry in &config.unclaimed_amounts {
+            held = held.checked_add(entry.amount).ok_or(ErrorCode::MathOverflow)?;
+        }
+        require!(held <= ctx.accounts.vault.amount, ErrorCode::SeedExceedsVault);
+
+        Ok(())
+    }
+
+    /// Upgrades a SplitConfig account to the current layout version
+    /// Permissionless and idempotent - payer covers any extra rent from realloc
+    pub fn migrate_split_config(ctx: Context<MigrateSplitConfig>) -> Result<()> {
+        let config_info = ctx.accounts.split_config.to_account_info();
+
+        // Older layouts can't be loaded as Account<SplitConfig>, so read version raw
+        let from_version = {
+            let data = config_info.try_borrow_data()?;
+            require!(
+                data.len() > 8 && data[..8] == *SplitConfig::DISCRIMINATOR,
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+            );
+            data[8]
+        };
+
+        if from_version >= CURRENT_VERSION {
+            return Ok(()); // Already migrated
+        }
+
+        // Grow account to current size, topping up rent exemption from payer
+        if config_info.data_len() < SPLIT_CONFIG_SIZE {
+            let required_lamports = Rent::get()?.minimum_balance(SPLIT_CONFIG_SIZE);
+            let shortfall = required_lamports.saturating_sub(config_info.lamports());
+            if shortfall > 0 {
+                let cpi_ctx = CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: config_info.clone(),
+                    },
+                );
+                system_program::transfer(cpi_ctx, shortfall)?;
+            }
+            config_info.resize(SPLIT_CONFIG_SIZE)?;
+        }
+
+        if from_version < RECIPIENT_LAYOUT_VERSION {
+            upgrade_legacy_recipients(&mut config_info.try_borrow_mut_data()?, from_version)?;
+        }
+
+        // Appended fields decode from the zero-filled tail as their defaults
+        let mut config = SplitConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+        if from_version < 29 {
+            // A zero timestamp would open recipient_takeover immediately
+            config.last_authority_activity_ts = Clock::get()?.unix_timestamp;
+        }
+        if from_version < 35 {
+            // Operational actions stay with the authority until delegated
+            config.ops_authority = config.authority;
+        }
+        config.version = CURRENT_VERSION;
+        config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+        emit!(SplitConfigMigrated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config_info.key(),
+            from_version,
+            to_version: CURRENT_VERSION,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Recipient gives up their share; freed bps go to remaining recipients pro rata
+    /// Existing unclaimed funds stay claimable by the renouncing recipient
+    /// Undistributed vault funds must be split first (they belong to the current set)
+    pub fn renounce_share(ctx: Context<RenounceShare>) -> Result<()> {
+        let config = &mut ctx.accounts.split_config;
+        let renouncer = ctx.accounts.recipient.key();
+        let (freed_bps, remaining_count) =
+            release_share(config, renouncer, ctx.accounts.vault.amount)?;
+
+        emit!(ShareRenounced {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            recipient: renouncer,
+            freed_bps,
+            remaining_recipients: remaining_count as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Recipient fully leaves the split: their share is renounced as in renounce_share and,
+    /// with `forfeit_dust`, a held entry below the config's min_payout is dropped so it
+    /// splits among the remaining set next round. Larger held funds stay claimable
+    pub fn exit_split(ctx: Context<RenounceShare>, forfeit_dust: bool) -> Result<()> {
+        let config = &mut ctx.accounts.split_config;
+        let recipient = ctx.accounts.recipient.key();
+        let (freed_bps, remaining_count) =
+            release_share(config, recipient, ctx.accounts.vault.amount)?;
+
+        let mut forfeited = 0;
+        if forfeit_dust {
+            let min_payout = config.min_payout;
+            if let Some(index) = config.unclaimed_amounts.iter()
+                .position(|u| u.recipient == recipient && u.amount < min_payout)
+            {
+                forfeited = config.unclaimed_amounts.remove(index).amount;
+                // Not a deposit: keep it out of the next BalanceIncrease
+                config.last_seen_balance = config.last_seen_balance.saturating_add(forfeited);
+            }
+        }
+
+        emit!(RecipientExited {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            recipient,
+            freed_bps,
+            forfeited,
+            remaining_recipients: remaining_count as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Creates the protocol-wide config PDA
+    /// Only callable by the program upgrade authority, who becomes admin
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        creation_fee_lamports: u64,
+        creation_fee_tokens: u64,
+    ) -> Result<()> {
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        protocol_config.admin = ctx.accounts.admin.key();
+        protocol_config.creation_fee_lamports = creation_fee_lamports;
+        protocol_config.creation_fee_tokens = creation_fee_tokens;
+        protocol_config.bump = ctx.bumps.protocol_config;
+
+        emit!(CreationFeeUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: protocol_config.admin,
+            creation_fee_lamports,
+            creation_fee_tokens,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Sets the one-time fee charged by create_split_config
+    /// Lamports go to the protocol wallet, tokens (config mint base units) to its ATA
+    pub fn set_creation_fee(
+        ctx: Context<UpdateProtocolConfig>,
+        creation_fee_lamports: u64,
+        creation_fee_tokens: u64,
+    ) -> Result<()> {
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        protocol_config.creation_fee_lamports = creation_fee_lamports;
+        protocol_config.creation_fee_tokens = creation_fee_tokens;
+
+        emit!(CreationFeeUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: protocol_config.admin,
+            creation_fee_lamports,
+            creation_fee_tokens,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Creates the program-wide circuit breaker PDA (starts unpaused)
+    /// Only callable by the program upgrade authority, who becomes admin
+    pub fn initialize_admin_config(ctx: Context<InitializeAdminConfig>) -> Result<()> {
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.admin = ctx.accounts.admin.key();
+        admin_config.global_paused = false;
+        admin_config.bump = ctx.bumps.admin_config;
+        Ok(())
+    }
+
+    /// Emergency stop across every config: execute_split (incl. chunked), claims and
+    /// recipient updates fail with GloballyPaused until unpaused
+    pub fn set_global_pause(ctx: Context<UpdateAdminConfig>, paused: bool) -> Result<()> {
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.global_paused = paused;
+
+        emit!(GlobalPauseUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: admin_config.admin,
+            paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Hands the circuit breaker to a new admin (e.g. a multisig)
+    pub fn transfer_admin(ctx: Context<UpdateAdminConfig>, new_admin: Pubkey) -> Result<()> {
+        require!(new_admin != Pubkey::default(), ErrorCode::ZeroAddress);
+
+        let admin_config = &mut ctx.accounts.admin_config;
+        let old_admin = admin_config.admin;
+        admin_config.admin = new_admin;
+
+        emit!(AdminTransferred {
+            schema_version: EVENT_SCHEMA_VERSION,
+            old_admin,
+            new_admin,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Read-only preview of a recipient's effective share and next payout
+    /// Returned via set_return_data (use `.view()` from clients)
+    pub fn get_effective_share(
+        ctx: Context<GetEffectiveShare>,
+        recipient: Pubkey,
+    ) -> Result<EffectiveShare> {
+        let config = &ctx.accounts.split_config;
+        let index = config.recipients.iter()
+            .position(|r| r.address == recipient)
+            .ok_or(ErrorCode::NotARecipient)?;
+        let now = Clock::get()?.unix_timestamp;
+
+        // A share the next round would hold instead of push previews as 0
+        let (effective_bps, estimated_amount) = if push_hold_reason(config, index, now).is_some() {
+            (0, 0)
+        } else {
+            let redistribution = if config.cliff_redistribute {
+                cliff_redistribution(config, now)?
+            } else {
+                None
+            };
+            let stored = config.recipients[index].percentage_bps;
+            let effective_bps = match redistribution {
+                Some((total_units, vested_units)) => scaled_share(stored as u64, total_units, vested_units)?
+                    .try_into()
+                    .map_err(|_| ErrorCode::MathOverflow)?,
+                None => stored,
+            };
+            let fresh_balance = undistributed_balance(config, ctx.accounts.vault.amount)?;
+            (effective_bps, round_share(config, index, fresh_balance, redistribution, now)?)
+        };
+
+        Ok(EffectiveShare {
+            recipient,
+            stored_bps: config.recipients[index].percentage_bps,
+            effective_bps,
+            estimated_amount,
+        })
+    }
+
+    /// Read-only view of what `recipient` can claim, for wallet UIs
+    /// Returns the total and each held entry with its expiry via set_return_data
+    /// Anyone may query any address; non-recipients get an empty result
+    pub fn get_claimable(ctx: Context<GetClaimable>, recipient: Pubkey) -> Result<Claimable> {
+        let mut total = 0u64;
+        let mut entries = Vec::new();
+        for entry in ctx.accounts.split_config.unclaimed_amounts.iter()
+            .filter(|u| u.recipient == recipient)
+        {
+            total = total.checked_add(entry.amount).ok_or(ErrorCode::MathOverflow)?;
+            entries.push(ClaimableEntry {
+                amount: entry.amount,
+                timestamp: entry.timestamp,
+                expires_at: entry.timestamp.saturating_add(UNCLAIMED_EXPIRY_SECONDS),
+            });
+        }
+
+        Ok(Claimable {
+            recipient,
+            total,
+            entries,
+        })
+    }
+
+    /// Read-only: the SOL tip `executor` would earn by calling execute_split now
+    /// Returns the lamports via set_return_data (use `.view()` from clients): 0 when
+    /// the call would fail or be a no-op (pause, allowlist, open chunked round, bounds,
+    /// cooldown, release gate, frozen vault) or the reserve can't cover the tip
+    pub fn preview_keeper_reward(ctx: Context<PreviewKeeperReward>, executor: Pubkey) -> Result<u64> {
+        let config = &ctx.accounts.split_config;
+        let tip = config.executor_tip_lamports;
+        if tip == 0 || config.executor_reserve_lamports < tip {
+            return Ok(0);
+        }
+        let callable = !ctx.accounts.admin_config.global_paused
+            && !config.paused
+            && (config.executor_allowlist.is_empty() || config.executor_allowlist.contains(&executor))
+            && !config.split_in_progress()
+            && !ctx.accounts.vault.is_frozen();
+        if !callable {
+            return Ok(0);
+        }
+
+        // Same dry run as execute_split_multi: a failing or no-op round pays no tip
+        let undistributed = undistributed_balance(config, ctx.accounts.vault.amount)?;
+        let vault_balance = releasable_balance(config, undistributed, Clock::get()?.unix_timestamp);
+        match check_execute_bounds(config, vault_balance) {
+            Ok(true) => Ok(tip),
+            _ => Ok(0),
+        }
+    }
+
+    /// Read-only: the audit log's distributions, oldest first
+    /// Returns the entries via set_return_data (use `.view()` from clients)
+    pub fn get_audit_log(ctx: Context<GetAuditLog>) -> Result<Vec<AuditEntry>> {
+        Ok(ctx.accounts.audit_log.chronological())
+    }
+
+    /// Read-only consistency check for monitoring and post-migration audits
+    /// Returns the first violated invariant (None = healthy) via set_return_data
+    pub fn verify_config(ctx: Context<VerifyConfig>) -> Result<Option<ConfigViolation>> {
+        let fee_exempt = ctx.accounts.fee_exempt_mints.is_exempt(&ctx.accounts.split_config.mint);
+        first_config_violation(&ctx.accounts.split_config, &ctx.accounts.vault, fee_exempt)
+    }
+
+    /// Sets the vault balance band execute_split operates in
+    /// Below min: no-op (funds accumulate). Above max: errors. 0 disables either bound
+    pub fn set_execute_bounds(
+        ctx: Context<UpdateSplitSettings>,
+        min_execute_amount: u64,
+        max_execute_amount: u64,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(
+            max_execute_amount == 0 || min_execute_amount <= max_execute_amount,
+            ErrorCode::InvalidExecuteBounds
+        );
+
+        let config = &mut ctx.accounts.split_config;
+        config.min_execute_amount = min_execute_amount;
+        config.max_execute_amount = max_execute_amount;
+
+        emit!(ExecuteBoundsUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            min_execute_amount,
+            max_execute_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Sets the minimum time between distributions; a real execution sooner than
+    /// `seconds` after the previous one fails with ExecuteCooldown. 0 disables the cooldown
+    pub fn set_min_execute_interval(ctx: Context<UpdateSplitSettings>, seconds: u32) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.min_execute_interval_seconds = seconds;
+
+        emit!(ExecuteIntervalUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            min_execute_interval_seconds: seconds,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Tops up the SOL reserve on the config PDA that executor tips are paid from
+    /// The lamports stay earmarked (rescue_lamports can't sweep them) until paid out
+    /// as tips or returned when the config is closed
+    pub fn fund_executor_reserve(ctx: Context<FundExecutorReserve>, lamports: u64) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(lamports > 0, ErrorCode::InvalidFundAmount);
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.split_config.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, lamports)?;
+
+        let config = &mut ctx.accounts.split_config;
+        config.executor_reserve_lamports = config.executor_reserve_lamports
+            .checked_add(lamports)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(ExecutorReserveFunded {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            lamports,
+            reserve_lamports: config.executor_reserve_lamports,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Sets the SOL tip paid from the executor reserve to whoever completes a
+    /// distribution, offsetting their transaction fee (0 disables it). Rounds the
+    /// reserve can't cover go ahead without a tip
+    pub fn set_executor_tip(ctx: Context<UpdateSplitSettings>, lamports: u64) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.executor_tip_lamports = lamports;
+
+        emit!(ExecutorTipUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            lamports,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Opts the config into an on-chain AuditLog of its last AUDIT_LOG_CAPACITY
+    /// distributions. Only before the first execution, so the log covers the config's
+    /// whole history; execute_split then requires the audit_log account
+    pub fn initialize_audit_log(ctx: Context<InitializeAuditLog>) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        require!(
+            config.execution_nonce == 0 && config.total_distributed == 0,
+            ErrorCode::ConfigNotFresh
+        );
+        config.audit_log_enabled = true;
+
+        let audit_log = &mut ctx.accounts.audit_log;
+        audit_log.config = config.key();
+        audit_log.head = 0;
+        audit_log.entries = Vec::new();
+        audit_log.bump = ctx.bumps.audit_log;
+
+        emit!(AuditLogEnabled {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            audit_log: audit_log.key(),
+            authority: config.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Opts the signer into an on-chain AuthorityIndex listing their configs (up to
+    /// MAX_INDEXED_CONFIGS), so dashboards can load them without getProgramAccounts.
+    /// Configs created earlier are backfilled from remaining_accounts; from then on,
+    /// pass the index to create_split_config and close_split_config to keep it current
+    pub fn initialize_authority_index<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeAuthorityIndex<'info>>,
+    ) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        require!(
+            ctx.remaining_accounts.len() <= MAX_INDEXED_CONFIGS,
+            ErrorCode::AuthorityIndexFull
+        );
+        require_unique_accounts(ctx.remaining_accounts)?;
+
+        let mut configs = Vec::with_capacity(ctx.remaining_accounts.len());
+        for info in ctx.remaining_accounts {
+            let config = Account::<SplitConfig>::try_from(info)?;
+            require_keys_eq!(config.authority, authority, ErrorCode::Unauthorized);
+            require_canonical_config(&config)?;
+            configs.push(config.key());
+        }
+
+        let index = &mut ctx.accounts.authority_index;
+        index.authority = authority;
+        index.configs = configs;
+        index.bump = ctx.bumps.authority_index;
+
+        emit!(AuthorityIndexInitialized {
+            schema_version: EVENT_SCHEMA_VERSION,
+            authority,
+            authority_index: index.key(),
+            configs_count: index.configs.len() as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Requires every claim to write a ClaimReceipt PDA (amount, mint, timestamp) that
+    /// accounting tools can read per recipient. Receipt rent is paid by the claimer, and
+    /// only claim_unclaimed, claim_all_unclaimed and claim_partial can write one
+    pub fn set_claim_receipts(ctx: Context<UpdateSplitSettings>, enabled: bool) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.claim_receipts = enabled;
+
+        emit!(ClaimReceiptsUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Sets the smallest share execute_split will push; smaller shares are held as unclaimed
+    /// 0 disables the threshold
+    pub fn set_min_payout(ctx: Context<UpdateSplitSettings>, min_payout: u64) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.min_payout = min_payout;
+
+        emit!(MinPayoutUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            min_payout,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Names the recipient that receives the exact remainder of each round (everything
+    /// not floored to the others, the reserve or the 1% fee), so rounding dust has a
+    /// predictable owner instead of going to the protocol. None restores the default
+    /// Cleared when the recipient set changes or the recipient leaves
+    pub fn set_remainder_recipient(ctx: Context<UpdateSplitSettings>, index: Option<u8>) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        let recipient = match index {
+            Some(index) => {
+                let recipient = config.recipients
+                    .get(index as usize)
+                    .ok_or(ErrorCode::InvalidRemainderRecipient)?;
+                require!(
+                    !recipient.frozen && recipient_share_units(config, index as usize)? > 0,
+                    ErrorCode::InvalidRemainderRecipient
+                );
+                Some(recipient.address)
+            }
+            None => None,
+        };
+        config.remainder_recipient_index = index;
+
+        emit!(RemainderRecipientUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            recipient,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Grace window between funds being held and becoming claimable, so the authority
+    /// can freeze a suspicious recipient first. Applies to every claim path; 0 = instant
+    pub fn set_claim_delay(ctx: Context<UpdateSplitSettings>, claim_delay_seconds: u32) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.claim_delay_seconds = claim_delay_seconds;
+
+        emit!(ClaimDelayUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            claim_delay_seconds,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Opt-in acceptance handshake: while set, a recipient's share is held as unclaimed
+    /// (and their claims refused) until they sign accept_recipient for their destination
+    pub fn set_require_acceptance(
+        ctx: Context<UpdateSplitSettings>,
+        require_acceptance: bool,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.require_acceptance = require_acceptance;
+
+        emit!(RequireAcceptanceUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            require_acceptance,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Strict fee: protocol gets exactly floor(vault * 1%), rounding dust is held as
+    /// unclaimed for the first active recipient. Default: protocol absorbs the dust
+    pub fn set_strict_fee(ctx: Context<UpdateSplitSettings>, strict_fee: bool) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.strict_fee = strict_fee;
+
+        emit!(StrictFeeUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            strict_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Freezes (or unfreezes) one recipient without touching shares
+    /// A frozen recipient's share is held as unclaimed and their claims are refused
+    pub fn set_recipient_frozen(
+        ctx: Context<UpdateSplitSettings>,
+        address: Pubkey,
+        frozen: bool,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        let recipient = config.recipients.iter_mut()
+            .find(|r| r.address == address)
+            .ok_or(ErrorCode::NotARecipient)?;
+        recipient.frozen = frozen;
+
+        emit!(RecipientFrozen {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            recipient: address,
+            frozen,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Softer than freezing: the recipient's share is held as unclaimed instead of pushed,
+    /// but claims keep working, e.g. while a mint authority has frozen their token account
+    pub fn set_recipient_push_paused(
+        ctx: Context<UpdateSplitSettings>,
+        address: Pubkey,
+        paused: bool,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        let recipient = config.recipients.iter_mut()
+            .find(|r| r.address == address)
+            .ok_or(ErrorCode::NotARecipient)?;
+        recipient.push_paused = paused;
+
+        emit!(RecipientPushPaused {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            recipient: address,
+            paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Recipient signs to prove control of their key and confirms their destination
+    /// token account exists for the config's mint. Acceptance survives updates that
+    /// keep the recipient's destination; a new destination must be accepted again
+    pub fn accept_recipient(ctx: Context<AcceptRecipient>) -> Result<()> {
+        let config = &mut ctx.accounts.split_config;
+        let address = ctx.accounts.recipient.key();
+        let mint = config.mint;
+        let recipient = config.recipients.iter_mut()
+            .find(|r| r.address == address)
+            .ok_or(ErrorCode::NotARecipient)?;
+
+        let destination_info = ctx.accounts.destination.to_account_info();
+        require_recipient_destination(
+            &destination_info,
+            recipient,
+            &mint,
+            &ctx.accounts.token_program.key(),
+        )?;
+        require!(!destination_info.data_is_empty(), ErrorCode::RecipientATADoesNotExist);
+        require!(
+            *destination_info.owner == ctx.accounts.token_program.key(),
+            ErrorCode::RecipientATAInvalidOwner
+        );
+
+        let destination = TokenAccount::try_deserialize(&mut &destination_info.try_borrow_data()?[..])
+            .map_err(|_| ErrorCode::RecipientATAInvalid)?;
+        require!(
+            destination.owner == destination_owner(recipient),
+            ErrorCode::RecipientATAWrongOwner
+        );
+        require!(destination.mint == mint, ErrorCode::RecipientATAWrongMint);
+
+        recipient.accepted = true;
+
+        emit!(RecipientAccepted {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            recipient: address,
+            destination: destination_info.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Streaming mode: each execution splits at most `stream_rate` base units per
+    /// second elapsed since the previous one, so keepers pay out a continuous salary
+    /// 0 returns to splitting the whole fresh balance. The stream starts now
+    pub fn set_stream_rate(ctx: Context<UpdateSplitSettings>, stream_rate: u64) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.stream_rate = stream_rate;
+        config.last_stream_ts = Clock::get()?.unix_timestamp;
+
+        emit!(StreamRateUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            stream_rate,
+            timestamp: config.last_stream_ts,
+        });
+
+        Ok(())
+    }
+
+    /// Chooses what happens to the share of a recipient whose cliff_ts hasn't passed:
+    /// held as unclaimed until the cliff (default), or redistributed pro rata to
+    /// vested recipients
+    pub fn set_cliff_mode(ctx: Context<UpdateSplitSettings>, redistribute: bool) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.cliff_redistribute = redistribute;
+
+        emit!(CliffModeUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            redistribute,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Gates execute_split behind a third-party arbiter (conditional escrow)
+    /// None removes the gate. Changing it always clears any pending release
+    pub fn set_release_authority(
+        ctx: Context<UpdateSplitSettings>,
+        release_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        let config = &mut ctx.accounts.split_config;
+        config.release_authority = release_authority;
+        config.released = false;
+
+        emit!(ReleaseAuthorityUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            release_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Arbiter approves (or revokes) the next distribution
+    /// The release is consumed when a split completes
+    pub fn signal_release(ctx: Context<SignalRelease>, released: bool) -> Result<()> {
+        let config = &mut ctx.accounts.split_config;
+        config.released = released;
+
+        emit!(ReleaseSignaled {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            release_authority: ctx.accounts.release_authority.key(),
+            released,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Switches between push (default) and pull-only distribution
+    /// Pull-only: execute_split records every share as unclaimed for recipients to claim
+    /// `hold_protocol_fee` also holds the protocol fee (claimable by the protocol wallet)
+    pub fn set_pull_only(
+        ctx: Context<UpdateSplitSettings>,
+        pull_only: bool,
+        hold_protocol_fee: bool,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(pull_only || !hold_protocol_fee, ErrorCode::PullOnlyConfig);
+
+        let config = &mut ctx.accounts.split_config;
+        config.pull_only = pull_only;
+        config.hold_protocol_fee = hold_protocol_fee;
+
+        emit!(PullOnlyUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            pull_only,
+            hold_protocol_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Restricts execute_split (and chunked execution) to the listed executors
+    /// Empty list restores permissionless execution
+    pub fn set_executor_allowlist(
+        ctx: Context<UpdateSplitSettings>,
+        executors: Vec<Pubkey>,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(executors.len() <= MAX_EXECUTORS, ErrorCode::TooManyExecutors);
+        for (i, executor) in executors.iter().enumerate() {
+            require!(*executor != Pubkey::default(), ErrorCode::ZeroAddress);
+            require!(!executors[i + 1..].contains(executor), ErrorCode::DuplicateExecutor);
+        }
+
+        let config = &mut ctx.accounts.split_config;
+        config.executor_allowlist = executors;
+
+        emit!(ExecutorAllowlistUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            executors: config.executor_allowlist.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Points the config at the off-chain agreement governing the split (IPFS/Arweave URI)
+    /// Empty string clears it
+    pub fn set_metadata_uri(ctx: Context<UpdateSplitSettings>, metadata_uri: String) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(metadata_uri.len() <= MAX_METADATA_URI_LEN, ErrorCode::MetadataUriTooLong);
+
+        let config = &mut ctx.accounts.split_config;
+        config.metadata_uri = metadata_uri;
+
+        emit!(MetadataUriUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            metadata_uri: config.metadata_uri.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Pre-flight check for execute_split: validates every recipient ATA without moving tokens
+    /// Emits RecipientValidation per recipient. Never mutates state or needs a vault balance
+    /// remaining_accounts: recipient ATAs (same order as recipients)
+    pub fn validate_recipients<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ValidateRecipients<'info>>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.split_config;
+        require!(
+            ctx.remaining_accounts.len() == config.recipients.len(),
+            ErrorCode::RecipientATACountMismatch
+        );
+
+        let mint = ctx.accounts.mint.key();
+        let token_program = *ctx.accounts.mint.to_account_info().owner;
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        for (recipient, recipient_ata_info) in config.recipients.iter().zip(ctx.remaining_accounts) {
+            let result = require_recipient_destination(recipient_ata_info, recipient, &mint, &token_program)
+                .map_err(|_| HeldReason::NotDestination)
+                .and_then(|_| {
+                    require_token_program(recipient_ata_info, &token_program)
+                        .map_err(|_| HeldReason::WrongProgram)
+                })
+                .and_then(|_| validate_recipient_ata(recipient_ata_info, recipient, &mint));
+            let reason = result.err();
+            let ok = reason.is_none();
+
+            emit!(RecipientValidation {
+                schema_version: EVENT_SCHEMA_VERSION,
+                config: config.key(),
+                recipient: recipient.address,
+                ok,
+                reason,
+                timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Setup convenience: idempotently creates every ATA-destination recipient's ATA and
+    /// the protocol ATA, payer covering rent, so execute_split never holds for a missing ATA
+    /// Permissionless. Emits RecipientAtasEnsured listing created vs already-existing ATAs
+    /// remaining_accounts: (ATA, wallet) per ATA-destination recipient in recipient order,
+    /// then (protocol ATA, protocol wallet)
+    pub fn ensure_recipient_atas<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EnsureRecipientAtas<'info>>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.split_config;
+        let mut wallets: Vec<Pubkey> = config.recipients.iter()
+            .filter(|r| r.destination == RecipientDestination::Ata)
+            .map(|r| r.address)
+            .collect();
+        wallets.push(ctx.accounts.protocol_fee_wallets.wallet_for(&config.mint));
+        require!(
+            ctx.remaining_accounts.len() == wallets.len() * 2,
+            ErrorCode::RecipientATACountMismatch
+        );
+
+        let mint = ctx.accounts.mint.key();
+        let token_program = ctx.accounts.token_program.key();
+        let mut created = Vec::new();
+        let mut existing = Vec::new();
+
+        for (wallet, pair) in wallets.iter().zip(ctx.remaining_accounts.chunks(2)) {
+            let (ata_info, wallet_info) = (&pair[0], &pair[1]);
+            require!(wallet_info.key() == *wallet, ErrorCode::RecipientATAWrongOwner);
+            require!(
+                ata_info.key() == get_associated_token_address_with_program_id(wallet, &mint, &token_program),
+                ErrorCode::RecipientATAInvalid
+            );
+
+            if !ata_info.data_is_empty() {
+                existing.push(ata_info.key());
+                continue;
+            }
+            associated_token::create_idempotent(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: ctx.accounts.payer.to_account_info(),
+                    associated_token: ata_info.clone(),
+                    authority: wallet_info.clone(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+            ))?;
+            created.push(ata_info.key());
+        }
+
+        emit!(RecipientAtasEnsured {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            payer: ctx.accounts.payer.key(),
+            created,
+            existing,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Drops zero-amount unclaimed entries to free slots under MAX_UNCLAIMED
+    /// Permissionless - only removes entries that hold nothing
+    pub fn compact_unclaimed(ctx: Context<CompactUnclaimed>) -> Result<()> {
+        let config = &mut ctx.accounts.split_config;
+        let before = config.unclaimed_amounts.len();
+        config.unclaimed_amounts.retain(|u| u.amount > 0);
+        let removed = before - config.unclaimed_amounts.len();
+
+        emit!(UnclaimedCompacted {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            removed: removed as u8,
+            remaining: config.unclaimed_amounts.len() as u8,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Escape hatch for abandoned splits: sweeps expired unclaimed funds to the authority,
+    /// then closes the vault and config
+    /// Every unclaimed entry must be older than UNCLAIMED_EXPIRY_SECONDS, and the vault must
+    /// hold nothing but those funds (execute_split first)
+    /// Multisig configs: co-signers in remaining_accounts
+    pub fn force_close_split_config(ctx: Context<ForceCloseSplitConfig>) -> Result<()> {
+        require_multisig_approval(
+            &ctx.accounts.split_config,
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            SensitiveOp::Close,
+        )?;
+        let config = &ctx.accounts.split_config;
+        require!(!config.split_in_progress(), ErrorCode::ChunkedSplitInProgress);
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut swept = 0u64;
+        for entry in &config.unclaimed_amounts {
+            let age = now.checked_sub(entry.timestamp).ok_or(ErrorCode::MathUnderflow)?;
+            require!(age >= UNCLAIMED_EXPIRY_SECONDS, ErrorCode::UnclaimedNotExpired);
+            swept = swept.checked_add(entry.amount).ok_or(ErrorCode::MathOverflow)?;
+        }
+        require!(ctx.accounts.vault.amount == swept, ErrorCode::UndistributedVaultFunds);
+
+        if swept > 0 {
+            transfer_from_vault(
+                &ctx.accounts.split_config,
+                &ctx.accounts.vault,
+                &ctx.accounts.mint,
+                ctx.accounts.authority_ata.to_account_info(),
+                &ctx.accounts.token_program,
+                swept,
+            )?;
+        }
+
+        let config = &ctx.accounts.split_config;
+        let seeds = &[
+            b"split_config".as_ref(),
+            config.authority.as_ref(),
+            config.mint.as_ref(),
+            &[config.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Vault rent goes back to the authority along with the config's
+        let cpi_accounts = token_interface::CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::close_account(cpi_ctx)?;
+        unindex_config(ctx.accounts.authority_index.as_deref_mut(), &config.key());
+
+        emit!(ForceClosed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            recipients: config.unclaimed_amounts.iter().map(|u| u.recipient).collect(),
+            amounts: config.unclaimed_amounts.iter().map(|u| u.amount).collect(),
+            swept,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Sweeps lamports above rent exemption off the split_config PDA to the ops authority
+    /// (SOL sent to the config address by mistake). Never touches token balances
+    pub fn rescue_lamports(ctx: Context<RescueLamports>) -> Result<()> {
+        record_ops_activity(&mut ctx.accounts.split_config, &ctx.accounts.ops_authority.key())?;
+        let config_info = ctx.accounts.split_config.to_account_info();
+        // The executor reserve is earmarked like rent
+        let rent_exempt = Rent::get()?.minimum_balance(config_info.data_len());
+        let amount = config_info.lamports()
+            .saturating_sub(rent_exempt)
+            .saturating_sub(ctx.accounts.split_config.executor_reserve_lamports);
+        require!(amount > 0, ErrorCode::NothingToRescue);
+
+        **config_info.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.ops_authority.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        emit!(AssetsRescued {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config_info.key(),
+            authority: ctx.accounts.split_config.authority,
+            mint: None,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+            ops_authority: ctx.accounts.ops_authority.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Sweeps a token account owned by the split_config PDA to the ops authority
+    /// Only for mints other than the config mint, so the vault and the unclaimed
+    /// funds it backs can never be rescued
+    pub fn rescue_tokens(ctx: Context<RescueTokens>) -> Result<()> {
+        record_ops_activity(&mut ctx.accounts.split_config, &ctx.accounts.ops_authority.key())?;
+        let amount = ctx.accounts.stray_account.amount;
+        require!(amount > 0, ErrorCode::NothingToRescue);
+
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.stray_account,
+            &ctx.accounts.stray_mint,
+            ctx.accounts.ops_token_account.to_account_info(),
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        emit!(AssetsRescued {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: ctx.accounts.split_config.key(),
+            authority: ctx.accounts.split_config.authority,
+            mint: Some(ctx.accounts.stray_mint.key()),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+            ops_authority: ctx.accounts.ops_authority.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Stops (or resumes) distribution for this config: execute_split, chunked
+    /// execution and finalize fail with ConfigPaused. Claims stay open
+    /// Multisig configs: co-signers in remaining_accounts
+    pub fn set_paused(ctx: Context<OpsAction>, paused: bool) -> Result<()> {
+        let ops_authority = ctx.accounts.ops_authority.key();
+        record_ops_activity(&mut ctx.accounts.split_config, &ops_authority)?;
+        require_multisig_approval(
+            &ctx.accounts.split_config,
+            &ops_authority,
+            ctx.remaining_accounts,
+            SensitiveOp::SetPaused,
+        )?;
+        let config = &mut ctx.accounts.split_config;
+        config.paused = paused;
+
+        emit!(ConfigPauseUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            paused,
+            timestamp: Clock::get()?.unix_timestamp,
+            ops_authority,
+        });
+
+        Ok(())
+    }
+
+    /// Proves the authority is still around without changing any setting
+    /// Every authority instruction does the same; this restarts the takeover window
+    pub fn authority_heartbeat(ctx: Context<UpdateSplitSettings>) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        Ok(())
+    }
+
+    /// Dead-man's switch: once the authority has been inactive for
+    /// TAKEOVER_INACTIVITY_SECONDS, recipients holding a strict majority of the unfrozen
+    /// shares (signing in remaining_accounts) restore permissionless distribution: unpause,
+    /// unfreeze every recipient (their held funds become claimable), and drop the executor
+    /// allowlist and release gate. Frozen recipients' signatures don't count, so a frozen
+    /// key can't lift its own freeze. The authority itself can't be replaced - it is a
+    /// seed of the config address
+    pub fn recipient_takeover(ctx: Context<RecipientTakeover>) -> Result<()> {
+        let config = &mut ctx.accounts.split_config;
+        let now = Clock::get()?.unix_timestamp;
+        let inactive_seconds = now.saturating_sub(config.last_authority_activity_ts);
+        require!(
+            inactive_seconds >= TAKEOVER_INACTIVITY_SECONDS,
+            ErrorCode::AuthorityStillActive
+        );
+
+        let mut signers: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut signed_units = 0u64;
+        for account in ctx.remaining_accounts {
+            let index = config.recipients.iter()
+                .position(|r| r.address == *account.key)
+                .ok_or(ErrorCode::NotARecipient)?;
+            require!(account.is_signer, ErrorCode::InsufficientRecipientWeight);
+            if signers.contains(account.key) || config.recipients[index].frozen {
+                continue;
+            }
+            signers.push(*account.key);
+            signed_units = signed_units
+                .checked_add(recipient_share_units(config, index)? as u64)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+        let mut total_units = 0u64;
+        for index in (0..config.recipients.len()).filter(|&i| !config.recipients[i].frozen) {
+            total_units = total_units
+                .checked_add(recipient_share_units(config, index)? as u64)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+        require!(
+            signed_units.checked_mul(2).ok_or(ErrorCode::MathOverflow)? > total_units,
+            ErrorCode::InsufficientRecipientWeight
+        );
+
+        config.paused = false;
+        for recipient in config.recipients.iter_mut() {
+            recipient.frozen = false;
+        }
+        config.executor_allowlist = Vec::new();
+        config.release_authority = None;
+
+        emit!(RecipientTakeoverExecuted {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            signers,
+            signed_units,
+            total_units,
+            inactive_seconds,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+
+    /// Enables, changes or (with an empty set and threshold 0) disables M-of-N approval
+    /// for sensitive ops: recipient updates, pause, emergency drain, close and this call.
+    /// The authority still signs; co-signing listed authorities go in remaining_accounts,
+    /// and the authority counts toward the threshold if listed
+    pub fn set_multisig(
+        ctx: Context<UpdateSplitSettings>,
+        authorities: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require_multisig_approval(
+            &ctx.accounts.split_config,
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            SensitiveOp::SetMultisig,
+        )?;
+
+        require!(authorities.len() <= MAX_AUTHORITIES, ErrorCode::InvalidMultisig);
+        require!(
+            threshold as usize <= authorities.len() && (threshold == 0) == authorities.is_empty(),
+            ErrorCode::InvalidMultisig
+        );
+        for (i, authority) in authorities.iter().enumerate() {
+            require!(*authority != Pubkey::default(), ErrorCode::ZeroAddress);
+            require!(!authorities[i + 1..].contains(authority), ErrorCode::InvalidMultisig);
+        }
+
+        let config = &mut ctx.accounts.split_config;
+        config.authorities = authorities.clone();
+        config.threshold = threshold;
+
+        emit!(MultisigUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            authorities,
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Hands operational actions (set_paused, emergency_drain, rescue_lamports,
+    /// rescue_tokens) to a separate key, so whoever can halt or drain a config needn't
+    /// be able to change its recipients. The authority can always reassign it
+    pub fn set_ops_authority(ctx: Context<UpdateSplitSettings>, ops_authority: Pubkey) -> Result<()> {
+        record_authority_activity(&mut ctx.accounts.split_config)?;
+        require!(ops_authority != Pubkey::default(), ErrorCode::ZeroAddress);
+        let config = &mut ctx.accounts.split_config;
+        let previous = config.ops_authority;
+        config.ops_authority = ops_authority;
+
+        emit!(OpsAuthorityUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            previous,
+            ops_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Break-glass: sends the entire vault to `destination`'s ATA, bypassing the split
+    /// The config must already be paused (set_paused), so this is always two steps.
+    /// Held unclaimed entries are cleared - their funds leave with the drain
+    /// Multisig configs: co-signers in remaining_accounts
+    pub fn emergency_drain(ctx: Context<EmergencyDrain>, destination: Pubkey) -> Result<()> {
+        let ops_authority = ctx.accounts.ops_authority.key();
+        record_ops_activity(&mut ctx.accounts.split_config, &ops_authority)?;
+        require_multisig_approval(
+            &ctx.accounts.split_config,
+            &ops_authority,
+            ctx.remaining_accounts,
+            SensitiveOp::EmergencyDrain,
+        )?;
+        let amount = ctx.accounts.vault.amount;
+        require!(amount > 0, ErrorCode::NothingToDrain);
+
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.destination_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        // The vault is empty: earmarks and any chunked round no longer refer to funds
+        let config = &mut ctx.accounts.split_config;
+        let cleared_unclaimed = config.unclaimed_amounts.len() as u8;
+        config.unclaimed_amounts.clear();
+        config.split_cursor = 0;
+        config.round_balance = 0;
+        config.round_distributed = 0;
+        config.round_held = 0;
+        config.round_fee_floor = 0;
+        config.last_seen_balance = 0;
+        config.pending_protocol_fee = 0;
+
+        emit!(EmergencyDrained {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            destination,
+            amount,
+            cleared_unclaimed,
+            timestamp: Clock::get()?.unix_timestamp,
+            ops_authority,
+        });
+
+        Ok(())
+    }
+
+    /// Creates the fee-exempt mint list, managed by the AdminConfig admin
+    pub fn initialize_fee_exempt_mints(ctx: Context<InitializeFeeExemptMints>) -> Result<()> {
+        let fee_exempt_mints = &mut ctx.accounts.fee_exempt_mints;
+        fee_exempt_mints.mints = Vec::new();
+        fee_exempt_mints.bump = ctx.bumps.fee_exempt_mints;
+        Ok(())
+    }
+
+    /// Exempts a mint from the protocol fee (partnership stablecoins)
+    /// Configs on an exempt mint may have recipients total 100%
+    pub fn add_fee_exempt_mint(ctx: Context<UpdateFeeExemptMints>, mint: Pubkey) -> Result<()> {
+        let fee_exempt_mints = &mut ctx.accounts.fee_exempt_mints;
+        require!(!fee_exempt_mints.is_exempt(&mint), ErrorCode::DuplicateFeeExemptMint);
+        require!(
+            fee_exempt_mints.mints.len() < MAX_FEE_EXEMPT_MINTS,
+            ErrorCode::TooManyFeeExemptMints
+        );
+        fee_exempt_mints.mints.push(mint);
+
+        emit!(FeeExemptMintUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: ctx.accounts.admin.key(),
+            mint,
+            exempt: true,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Ends a mint's fee exemption. Configs on it that total 100% fail to execute
+    /// (FeeExemptionRevoked) until their recipients are updated back to 99%
+    pub fn remove_fee_exempt_mint(ctx: Context<UpdateFeeExemptMints>, mint: Pubkey) -> Result<()> {
+        let fee_exempt_mints = &mut ctx.accounts.fee_exempt_mints;
+        let index = fee_exempt_mints.mints.iter()
+            .position(|m| *m == mint)
+            .ok_or(ErrorCode::MintNotFeeExempt)?;
+        fee_exempt_mints.mints.remove(index);
+
+        emit!(FeeExemptMintUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: ctx.accounts.admin.key(),
+            mint,
+            exempt: false,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Creates the per-mint protocol fee wallet map, managed by the AdminConfig admin
+    /// Mints without an entry keep paying PROTOCOL_WALLET
+    pub fn initialize_protocol_fee_wallets(ctx: Context<InitializeProtocolFeeWallets>) -> Result<()> {
+        let protocol_fee_wallets = &mut ctx.accounts.protocol_fee_wallets;
+        protocol_fee_wallets.wallets = Vec::new();
+        protocol_fee_wallets.bump = ctx.bumps.protocol_fee_wallets;
+        Ok(())
+    }
+
+    /// Routes a mint's execution fees to `wallet`'s ATA (issuer-specific settlement
+    /// sub-accounts), replacing any earlier entry. The one-time creation fee still
+    /// goes to PROTOCOL_WALLET
+    pub fn set_protocol_fee_wallet(
+        ctx: Context<UpdateProtocolFeeWallets>,
+        mint: Pubkey,
+        wallet: Pubkey,
+    ) -> Result<()> {
+        let protocol_fee_wallets = &mut ctx.accounts.protocol_fee_wallets;
+        match protocol_fee_wallets.wallets.iter_mut().find(|w| w.mint == mint) {
+            Some(entry) => entry.wallet = wallet,
+            None => {
+                require!(
+                    protocol_fee_wallets.wallets.len() < MAX_PROTOCOL_FEE_WALLETS,
+                    ErrorCode::TooManyProtocolFeeWallets
+                );
+                protocol_fee_wallets.wallets.push(ProtocolFeeWallet { mint, wallet });
+            }
+        }
+
+        emit!(ProtocolFeeWalletUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: ctx.accounts.admin.key(),
+            mint,
+            wallet,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Drops a mint's entry; its fees go back to PROTOCOL_WALLET
+    pub fn remove_protocol_fee_wallet(ctx: Context<UpdateProtocolFeeWallets>, mint: Pubkey) -> Result<()> {
+        let protocol_fee_wallets = &mut ctx.accounts.protocol_fee_wallets;
+        let index = protocol_fee_wallets.wallets.iter()
+            .position(|w| w.mint == mint)
+            .ok_or(ErrorCode::ProtocolFeeWalletNotSet)?;
+        protocol_fee_wallets.wallets.remove(index);
+
+        emit!(ProtocolFeeWalletUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: ctx.accounts.admin.key(),
+            mint,
+            wallet: PROTOCOL_WALLET,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Creates a mint's (empty) volume fee tier table; until tiers are set its configs
+    /// pay the full 1% fee
+    pub fn initialize_fee_tiers(ctx: Context<InitializeFeeTiers>, mint: Pubkey) -> Result<()> {
+        let fee_tiers = &mut ctx.accounts.fee_tiers;
+        fee_tiers.mint = mint;
+        fee_tiers.tiers = Vec::new();
+        fee_tiers.bump = ctx.bumps.fee_tiers;
+        fee_tiers.min_fee = 0;
+        Ok(())
+    }
+
+    /// Replaces a mint's volume fee tiers. A config pays the fee of the highest tier whose
+    /// min_volume its lifetime total_distributed has reached. Tiers must be in ascending
+    /// min_volume order with non-increasing fees of at most 1%; an empty table restores
+    /// the full fee
+    pub fn set_fee_tiers(ctx: Context<UpdateFeeTiers>, tiers: Vec<FeeTier>) -> Result<()> {
+        require!(tiers.len() <= MAX_FEE_TIERS, ErrorCode::InvalidFeeTiers);
+        require!(
+            tiers.iter().all(|t| t.fee_bps <= PROTOCOL_FEE_BPS),
+            ErrorCode::InvalidFeeTiers
+        );
+        require!(
+            tiers.windows(2).all(|w| w[0].min_volume < w[1].min_volume && w[0].fee_bps >= w[1].fee_bps),
+            ErrorCode::InvalidFeeTiers
+        );
+
+        let fee_tiers = &mut ctx.accounts.fee_tiers;
+        fee_tiers.tiers = tiers.clone();
+
+        emit!(FeeTiersUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: ctx.accounts.admin.key(),
+            mint: fee_tiers.mint,
+            tiers,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Sets a mint's protocol fee floor in base units (0 = none). A round whose fee would
+    /// fall below it pays the floor instead, capped at MAX_FEE_FLOOR_BPS of the round, with
+    /// the top-up taken off the top before recipients split the rest
+    pub fn set_min_protocol_fee(ctx: Context<UpdateFeeTiers>, min_fee: u64) -> Result<()> {
+        let fee_tiers = &mut ctx.accounts.fee_tiers;
+        fee_tiers.min_fee = min_fee;
+
+        emit!(MinProtocolFeeUpdated {
+            schema_version: EVENT_SCHEMA_VERSION,
+            admin: ctx.accounts.admin.key(),
+            mint: fee_tiers.mint,
+            min_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Sends a config's pending_protocol_fee to the protocol ATA without running a split
+    /// Admin-gated; recipient shares, unclaimed entries and chunked rounds are untouched
+    /// because the pending fee is already excluded from the undistributed balance
+    pub fn sweep_protocol_fee(ctx: Context<SweepProtocolFee>) -> Result<()> {
+        let amount = ctx.accounts.split_config.pending_protocol_fee;
+        require!(amount > 0, ErrorCode::NoPendingProtocolFee);
+
+        transfer_from_vault(
+            &ctx.accounts.split_config,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            ctx.accounts.protocol_ata.to_account_info(),
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+
+        let config = &mut ctx.accounts.split_config;
+        config.pending_protocol_fee = 0;
+
+        emit!(ProtocolFeeSwept {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            admin: ctx.accounts.admin.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Closes an empty config and its vault, returning both rents (and any executor
+    /// reserve) to the authority
+    /// Requires an empty vault and no held entries; abandoned configs with expired held
+    /// funds use force_close_split_config instead
+    /// Multisig configs: co-signers in remaining_accounts
+    pub fn close_split_config(ctx: Context<CloseSplitConfig>) -> Result<()> {
+        require_multisig_approval(
+            &ctx.accounts.split_config,
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            SensitiveOp::Close,
+        )?;
+        let config = &ctx.accounts.split_config;
+        require!(!config.split_in_progress(), ErrorCode::ChunkedSplitInProgress);
+        require!(
+            config.unclaimed_amounts.iter().all(|u| u.amount == 0),
+            ErrorCode::UnclaimedFundsExist
+        );
+        require!(ctx.accounts.vault.amount == 0, ErrorCode::VaultNotEmpty);
+
+        let seeds = &[
+            b"split_config".as_ref(),
+            config.authority.as_ref(),
+            config.mint.as_ref(),
+            &[config.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = token_interface::CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::close_account(cpi_ctx)?;
+        unindex_config(ctx.accounts.authority_index.as_deref_mut(), &config.key());
+
+        emit!(SplitConfigClosed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            authority: config.authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+/// Drops a closed config from its authority's index, when one was passed
+fn unindex_config(index: Option<&mut Account<AuthorityIndex>>, config: &Pubkey) {
+    if let Some(index) = index {
+        index.configs.retain(|c| c != config);
+    }
+}
+
+/// Token account a recipient must be paid into: their explicit account, or the
+/// ATA derived for (address, mint, token_program)
+fn recipient_destination(recipient: &Recipient, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    match recipient.destination {
+        RecipientDestination::Ata => {
+            get_associated_token_address_with_program_id(&recipient.address, mint, token_program)
+        }
+        RecipientDestination::ExplicitAccount(token_account) => token_account,
+        // Child vaults are the ATA of the child config PDA
+        RecipientDestination::SubSplit(child) => {
+            get_associated_token_address_with_program_id(&child, mint, token_program)
+        }
+    }
+}
+
+/// Wallet that must own the recipient's destination token account
+fn destination_owner(recipient: &Recipient) -> Pubkey {
+    match recipient.destination {
+        RecipientDestination::SubSplit(child) => child,
+        _ => recipient.address,
+    }
+}
+
+/// Validates a SubSplit recipient's child: a SplitConfig on the same mint, and not
+/// the parent itself (longer cycles only move funds between vaults, each hop paying the fee)
+fn require_child_config<'info>(
+    child_info: &'info AccountInfo<'info>,
+    child: &Pubkey,
+    mint: &Pubkey,
+    parent: &Pubkey,
+) -> Result<()> {
+    require!(
+        child_info.key() == *child && child != parent,
+        ErrorCode::InvalidSubSplit
+    );
+    let child_config = Account::<'info, SplitConfig>::try_from(child_info)
+        .map_err(|_| ErrorCode::InvalidSubSplit)?;
+    require!(child_config.mint == *mint, ErrorCode::RecipientATAWrongMint);
+    Ok(())
+}
+
+/// Requires `account_info` to be the recipient's destination account,
+/// making the recipient <-> account mapping independent of client ordering
+fn require_recipient_destination(
+    account_info: &AccountInfo,
+    recipient: &Recipient,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<()> {
+    require!(
+        account_info.key() == recipient_destination(recipient, mint, token_program),
+        ErrorCode::RecipientATAMismatch
+    );
+    Ok(())
+}
+
+/// Rejects a destination that is the config's own vault (a recipient, reserve or tip
+/// address equal to the config PDA). A self-transfer would count as paid while the
+/// tokens stay in the vault to be split again
+fn require_not_vault(account_info: &AccountInfo, vault: &Pubkey) -> Result<()> {
+    require_keys_neq!(account_info.key(), *vault, ErrorCode::RecipientIsVault);
+    Ok(())
+}
+
+/// Rejects an existing token account owned by the other token program (a Token account
+/// for a Token-2022 mint or vice versa). Such an account can never hold the config mint,
+/// so execution fails with a precise error instead of holding the share as wrong-mint.
+/// Only explicit destinations can hit this: ATAs derive from the token program
+fn require_token_program(account_info: &AccountInfo, token_program: &Pubkey) -> Result<()> {
+    let other_token_program = account_info.owner != token_program
+        && (account_info.owner == &token::ID || account_info.owner == &token_2022::ID);
+    require!(!other_token_program, ErrorCode::RecipientATAWrongProgram);
+    Ok(())
+}
+
+/// Emits RecipientRegistered for every recipient so log-only indexers can rebuild the set
+fn emit_recipients_registered(
+    config: Pubkey,
+    recipients: &[Recipient],
+    share_ppm: &[u32],
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    for (index, recipient) in recipients.iter().enumerate() {
+        emit!(RecipientRegistered {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config,
+            address: recipient.address,
+            percentage_bps: recipient.percentage_bps,
+            share_ppm: share_ppm.get(index).copied().unwrap_or(0),
+            index: index as u8,
+            timestamp,
+        });
+    }
+    Ok(())
+}
+
+/// Emits ExplicitDestinationSet for every recipient paid into a non-ATA account
+fn emit_explicit_destinations(config: Pubkey, recipients: &[Recipient]) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    for recipient in recipients {
+        if let RecipientDestination::ExplicitAccount(token_account) = recipient.destination {
+            emit!(ExplicitDestinationSet {
+                schema_version: EVENT_SCHEMA_VERSION,
+                config,
+                recipient: recipient.address,
+                token_account,
+                timestamp,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks each incoming recipient's destination (one remaining account per recipient,
+/// in order) exists, belongs to them and holds the config mint; SubSplit destinations
+/// must be child configs on the same mint
+fn require_new_destinations<'info>(
+    destinations: &'info [AccountInfo<'info>],
+    recipients: &[Recipient],
+    config: &Account<SplitConfig>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<()> {
+    for (recipient, recipient_ata_info) in recipients.iter().zip(destinations) {
+        if let RecipientDestination::SubSplit(child) = recipient.destination {
+            require_child_config(recipient_ata_info, &child, &config.mint, &config.key())?;
+            continue;
+        }
+
+        require_recipient_destination(
+            recipient_ata_info,
+            recipient,
+            &config.mint,
+            vault.to_account_info().owner,
+        )?;
+        require_not_vault(recipient_ata_info, &vault.key())?;
+
+        require!(!recipient_ata_info.data_is_empty(), ErrorCode::RecipientATADoesNotExist);
+
+        let recipient_ata = InterfaceAccount::<'info, TokenAccount>::try_from(recipient_ata_info)
+            .map_err(|_| ErrorCode::RecipientATAInvalid)?;
+
+        require!(
+            recipient_ata.owner == destination_owner(recipient),
+            ErrorCode::RecipientATAWrongOwner
+        );
+        require!(recipient_ata.mint == config.mint, ErrorCode::RecipientATAWrongMint);
+    }
+    Ok(())
+}
+
+/// Swaps in the queued recipient set once its effective time has passed and, as for
+/// update_split_config, nothing is left to split: funds deposited under the current set
+/// are paid at its shares first. Called as a round starts and after it completes, so no
+/// round (chunked or not) straddles two sets and no layout is checked against the new set
+fn promote_pending_recipients(
+    config: &mut Account<SplitConfig>,
+    undistributed: u64,
+    now: i64,
+) -> Result<()> {
+    let pending = match config.pending_recipients.take() {
+        Some(pending) if now >= pending.effective_ts && undistributed == 0 => pending,
+        not_due => {
+            config.pending_recipients = not_due;
+            return Ok(());
+        }
+    };
+
+    let mut recipients = pending.recipients;
+    carry_acceptance(&config.recipients, &mut recipients);
+    // Removed recipients keep their held entries (claimable by address only)
+    for (recipient, amount) in orphaned_unclaimed(config, &recipients)? {
+        emit!(OrphanedUnclaimed {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            recipient,
+            amount,
+            timestamp: now,
+        });
+    }
+
+    let old_recipients_count = config.recipients.len() as u8;
+    config.recipients = recipients;
+    config.share_ppm = pending.share_ppm;
+    config.remainder_recipient_index = None;
+    config.recipients_epoch = config.recipients_epoch
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(RecipientsPromoted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        config: config.key(),
+        old_recipients_count,
+        new_recipients_count: config.recipients.len() as u8,
+        recipients_epoch: config.recipients_epoch,
+        effective_ts: pending.effective_ts,
+        timestamp: now,
+    });
+    emit_recipients_registered(config.key(), &config.recipients, &config.share_ppm)?;
+    emit_explicit_destinations(config.key(), &config.recipients)?;
+    Ok(())
+}
+
+/// Resets `accepted` on an incoming recipient set, keeping it only for recipients whose
+/// address and destination match an accepted entry of the current set (callers can't
+/// pre-accept on a recipient's behalf)
+fn carry_acceptance(current: &[Recipient], incoming: &mut [Recipient]) {
+    for recipient in incoming.iter_mut() {
+        recipient.accepted = current.iter().any(|r| {
+            r.accepted && r.address == recipient.address && r.destination == recipient.destination
+        });
+    }
+}
+
+/// Rewrites a recipients Vec stored in an older element layout (v5 added
+/// `destination`, v8 `label`, v15 `frozen`, v19 `cliff_ts`, v28 `accepted`, v30
+/// `push_paused`) and shifts the rest of the account right
+/// (the resized tail is zero padding)
+fn upgrade_legacy_recipients(data: &mut [u8], from_version: u8) -> Result<()> {
+    let mut legacy = &data[RECIPIENTS_OFFSET..];
+    let available = legacy.len();
+    let recipients: Vec<Recipient> = if from_version < 5 {
+        Vec::<RecipientV1>::deserialize(&mut legacy)?
+            .into_iter()
+            .map(|r| Recipient {
+                address: r.address,
+                percentage_bps: r.percentage_bps,
+                destination: RecipientDestination::Ata,
+                label: [0; 16],
+                frozen: false,
+                cliff_ts: 0,
+                accepted: false,
+                push_paused: false,
+            })
+            .collect()
+    } else if from_version < 8 {
+        Vec::<RecipientV5>::deserialize(&mut legacy)?
+            .into_iter()
+            .map(|r| Recipient {
+                address: r.address,
+                percentage_bps: r.percentage_bps,
+                destination: r.destination,
+                label: [0; 16],
+                frozen: false,
+                cliff_ts: 0,
+                accepted: false,
+                push_paused: false,
+            })
+            .collect()
+    } else if from_version < 15 {
+        Vec::<RecipientV8>::deserialize(&mut legacy)?
+            .into_iter()
+            .map(|r| Recipient {
+                address: r.address,
+                percentage_bps: r.percentage_bps,
+                destination: r.destination,
+                label: r.label,
+                frozen: false,
+                cliff_ts: 0,
+                accepted: false,
+                push_paused: false,
+            })
+            .collect()
+    } else if from_version < 19 {
+        Vec::<RecipientV15>::deserialize(&mut legacy)?
+            .into_iter()
+            .map(|r| Recipient {
+                address: r.address,
+                percentage_bps: r.percentage_bps,
+                destination: r.destination,
+                label: r.label,
+                frozen: r.frozen,
+                cliff_ts: 0,
+                accepted: false,
+                push_paused: false,
+            })
+            .collect()
+    } else if from_version < 28 {
+        Vec::<RecipientV19>::deserialize(&mut legacy)?
+            .into_iter()
+            .map(|r| Recipient {
+                address: r.address,
+                percentage_bps: r.percentage_bps,
+                destination: r.destination,
+                label: r.label,
+                frozen: r.frozen,
+                cliff_ts: r.cliff_ts,
+                accepted: false,
+                push_paused: false,
+            })
+            .collect()
+    } else {
+        Vec::<RecipientV28>::deserialize(&mut legacy)?
+            .into_iter()
+            .map(|r| Recipient {
+                address: r.address,
+                percentage_bps: r.percentage_bps,
+                destination: r.destination,
+                label: r.label,
+                frozen: r.frozen,
+                cliff_ts: r.cliff_ts,
+                accepted: r.accepted,
+                push_paused: false,
+            })
+            .collect()
+    };
+    require!(recipients.len() <= MAX_RECIPIENTS, ErrorCode::InvalidRecipientCount);
+    let legacy_end = RECIPIENTS_OFFSET + (available - legacy.len());
+
+    let mut upgraded = Vec::with_capacity(data.len() - RECIPIENTS_OFFSET);
+    recipients.serialize(&mut upgraded)?;
+    let grown = upgraded.len() - (legacy_end - RECIPIENTS_OFFSET);
+    upgraded.extend_from_slice(&data[legacy_end..data.len() - grown]);
+
+    data[RECIPIENTS_OFFSET..].copy_from_slice(&upgraded);
+    Ok(())
+}
+
+/// Pins the transfer path to the config's own token accounts: the vault must be the
+/// recorded vault and hold the config mint, and the mint must be the config mint.
+/// transfer_checked then reads decimals from that mint, so they cannot be substituted.
+/// Several accounts structs leave the vault unconstrained, so this is the boundary there
+fn require_config_token_accounts(
+    config: &SplitConfig,
+    vault: &InterfaceAccount<TokenAccount>,
+    mint: &InterfaceAccount<Mint>,
+) -> Result<()> {
+    require_keys_eq!(vault.key(), config.vault, ErrorCode::InvalidVault);
+    require_keys_eq!(vault.mint, config.mint, ErrorCode::VaultMintMismatch);
+    require_keys_eq!(mint.key(), config.mint, ErrorCode::VaultMintMismatch);
+    Ok(())
+}
+
+/// Fails up front when the mint's freeze authority has frozen the vault: every
+/// transfer out would fail, and holding each share as unclaimed would only hide why
+fn require_vault_not_frozen(vault: &InterfaceAccount<TokenAccount>) -> Result<()> {
+    require!(!vault.is_frozen(), ErrorCode::VaultFrozen);
+    Ok(())
+}
+
+/// Re-derives the split_config PDA and rejects any account that is not the canonical
+/// address (and bump) for its stored authority and mint. Anchor's seeds constraint
+/// already enforces this; the explicit check on fund-moving paths keeps the boundary
+/// if an accounts struct is ever refactored
+fn require_canonical_config(config: &Account<SplitConfig>) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"split_config", config.authority.as_ref(), config.mint.as_ref()],
+        &crate::ID,
+    );
+    require!(
+        config.key() == expected && config.bump == bump,
+        ErrorCode::NonCanonicalSplitConfig
+    );
+    Ok(())
+}
+
+/// Inspects a Token-2022 mint's extensions for ones that undermine or break the split
+/// (SPL Token mints have none)
+fn require_supported_mint(mint_info: &AccountInfo, allow_permanent_delegate: bool) -> Result<()> {
+    if mint_info.owner != &token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+    let extensions = mint.get_extension_types()?;
+
+    // A permanent delegate can move vault funds without the PDA's signature
+    require!(
+        allow_permanent_delegate || !extensions.contains(&ExtensionType::PermanentDelegate),
+        ErrorCode::UnsafeMintExtension
+    );
+
+    // Hook programs need extra accounts that execute_split doesn't pass, so every
+    // transfer would fail and be held. An authority could also enable a hook later
+    if let Ok(hook) = mint.get_extension::<TransferHook>() {
+        require!(
+            hook.program_id.0 == Pubkey::default() && hook.authority.0 == Pubkey::default(),
+            ErrorCode::TransferHookUnsupported
+        );
+    }
+    Ok(())
+}
+
+/// UI value of `amount` with interest accrued to `now`, and the current rate, for
+/// Token-2022 mints with InterestBearingConfig (None for every other mint)
+fn interest_bearing_ui_amount(
+    mint: &InterfaceAccount<Mint>,
+    amount: u64,
+    now: i64,
+) -> Result<Option<(String, i16)>> {
+    let mint_info = mint.to_account_info();
+    if mint_info.owner != &token_2022::ID {
+        return Ok(None);
+    }
+
+    let data = mint_info.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    let Ok(interest) = state.get_extension::<InterestBearingConfig>() else {
+        return Ok(None);
+    };
+    let ui_amount = interest
+        .amount_to_ui_amount(amount, mint.decimals, now)
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok(Some((ui_amount, i16::from(interest.current_rate))))
+}
+
+/// Multisig configs (threshold > 0): counts distinct listed authorities among the
+/// signing authority and `cosigners`, requiring at least `threshold`. Every co-signer
+/// must be a signing, listed authority; single-authority configs accept none
+fn require_multisig_approval(
+    config: &Account<SplitConfig>,
+    authority: &Pubkey,
+    cosigners: &[AccountInfo],
+    operation: SensitiveOp,
+) -> Result<()> {
+    if config.threshold == 0 {
+        require!(cosigners.is_empty(), ErrorCode::InvalidCosigner);
+        return Ok(());
+    }
+
+    let mut approvers = Vec::with_capacity(cosigners.len() + 1);
+    if config.authorities.contains(authority) {
+        approvers.push(*authority);
+    }
+    for cosigner in cosigners {
+        require!(
+            cosigner.is_signer && config.authorities.contains(cosigner.key),
+            ErrorCode::InvalidCosigner
+        );
+        if !approvers.contains(cosigner.key) {
+            approvers.push(*cosigner.key);
+        }
+    }
+    require!(
+        approvers.len() >= config.threshold as usize,
+        ErrorCode::InsufficientApprovals
+    );
+
+    emit!(MultisigApproved {
+        schema_version: EVENT_SCHEMA_VERSION,
+        config: config.key(),
+        operation,
+        approvers,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Stamps the authority as active, restarting the recipient_takeover inactivity window
+fn record_authority_activity(config: &mut SplitConfig) -> Result<()> {
+    config.last_authority_activity_ts = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+/// Operational actions count as authority activity only when the authority signed them;
+/// a delegated ops key being active doesn't prove the authority is
+fn record_ops_activity(config: &mut SplitConfig, signer: &Pubkey) -> Result<()> {
+    if *signer == config.authority {
+        record_authority_activity(config)?;
+    }
+    Ok(())
+}
+
+/// Zeroes `recipient`'s share and hands the freed bps to the rest pro rata
+/// Shared by renounce_share and exit_split; undistributed funds must be split first
+/// At least MIN_RECIPIENTS_AFTER_RELEASE recipients must keep a share
+/// Returns (freed bps, recipients left with a share)
+fn release_share(config: &mut SplitConfig, recipient: Pubkey, vault_amount: u64) -> Result<(u16, usize)> {
+    require!(!config.split_in_progress(), ErrorCode::ChunkedSplitInProgress);
+    require!(config.precision == Precision::Bps, ErrorCode::UnsupportedPrecision);
+    require!(
+        undistributed_balance(config, vault_amount)? == 0,
+        ErrorCode::StaleRecipientsForFunds
+    );
+
+    let index = config.recipients.iter()
+        .position(|r| r.address == recipient && r.percentage_bps > 0)
+        .ok_or(ErrorCode::NotARecipient)?;
+
+    let remaining_count = config.recipients.iter()
+        .filter(|r| r.address != recipient && r.percentage_bps > 0)
+        .count();
+    require!(remaining_count >= MIN_RECIPIENTS_AFTER_RELEASE, ErrorCode::TooFewRemainingRecipients);
+
+    let freed_bps = config.recipients[index].percentage_bps;
+    config.recipients[index].percentage_bps = 0;
+    if config.remainder_recipient_index == Some(index as u8) {
+        config.remainder_recipient_index = None;
+    }
+    redistribute_bps(&mut config.recipients, freed_bps)?;
+    config.recipients_epoch = config.recipients_epoch
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok((freed_bps, remaining_count))
+}
+
+/// Held totals of current recipients missing from `new_recipients`, one per recipient
+fn orphaned_unclaimed(config: &SplitConfig, new_recipients: &[Recipient]) -> Result<Vec<(Pubkey, u64)>> {
+    let mut orphaned: Vec<(Pubkey, u64)> = Vec::new();
+    for recipient in &config.recipients {
+        if new_recipients.iter().any(|r| r.address == recipient.address) {
+            continue;
+        }
+        let mut held = 0u64;
+        for entry in config.unclaimed_amounts.iter().filter(|u| u.recipient == recipient.address) {
+            held = held.checked_add(entry.amount).ok_or(ErrorCode::MathOverflow)?;
+        }
+        if held > 0 {
+            orphaned.push((recipient.address, held));
+        }
+    }
+    Ok(orphaned)
+}
+
+/// New recipient sets must be strictly ascending by address, so the remaining_accounts
+/// order for execute_split follows from the set alone (this also rules out duplicates)
+fn require_sorted_recipients(recipients: &[Recipient]) -> Result<()> {
+    for pair in recipients.windows(2) {
+        require!(pair[0].address != pair[1].address, ErrorCode::DuplicateRecipient);
+        require!(pair[0].address < pair[1].address, ErrorCode::RecipientsNotSorted);
+    }
+    Ok(())
+}
+
+/// execute_split body after the canonical-PDA and nonce checks, shared with execute_split_multi
+fn split_vault<'info>(
+    accounts: &mut ExecuteSplit<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    create_atas: bool,
+    tip_recipient: Option<Pubkey>,
+    tip_bps: u16,
+    target_amount: Option<u64>,
+) -> Result<ExecuteResult> {
+    require!(
+        !accounts.split_config.split_in_progress(),
+        ErrorCode::ChunkedSplitInProgress
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let undistributed = undistributed_balance(&accounts.split_config, accounts.vault.amount)?;
+    promote_pending_recipients(&mut accounts.split_config, undistributed, now)?;
+    record_balance_increase(&mut accounts.split_config, undistributed)?;
+
+    let releasable = releasable_balance(&accounts.split_config, undistributed, now);
+    // A committed target is split exactly; anything above it waits for the next round
+    let vault_balance = match target_amount {
+        Some(target) => {
+            require!(target <= releasable, ErrorCode::TargetAboveBalance);
+            target
+        }
+        None => releasable,
+    };
+    if !check_execute_bounds(&accounts.split_config, vault_balance)? {
+        // Nothing to split, but a fee deferred earlier can go out once the ATA exists
+        if accounts.split_config.pending_protocol_fee > 0 {
+            settle_protocol_fee(accounts, remaining_accounts, 0)?;
+        }
+        return Ok(ExecuteResult::default()); // No-op if vault empty or below minimum (funds accumulate)
+    }
+    accounts.split_config.last_stream_ts = now;
+
+    let recipients_len = accounts.split_config.recipients.len();
+
+    // Exact layout: ATAs, wallets (create_atas), tip ATA (tip), reserve ATA (reserve),
+    // protocol ATA (unless passed as the named protocol_ata account)
+    let expected_accounts = recipients_len
+        + if create_atas { recipients_len } else { 0 }
+        + if tip_recipient.is_some() { 1 } else { 0 }
+        + reserve_slots(&accounts.split_config)
+        + protocol_slots(accounts);
+    require!(
+        remaining_accounts.len() == expected_accounts,
+        ErrorCode::RecipientATACountMismatch
+    );
+    require_unique_accounts(remaining_accounts)?;
+
+    let recipient_wallets = if create_atas {
+        let wallets = remaining_accounts
+            .get(recipients_len..recipients_len * 2)
+            .ok_or(ErrorCode::RecipientATACountMismatch)?;
+        Some(wallets)
+    } else {
+        None
+    };
+
+    // Tip comes off the top; the rest splits normally
+    let tip_amount = match tip_recipient {
+        Some(tip_recipient) => pay_tip(
+            accounts,
+            remaining_accounts,
+            tip_recipient,
+            tip_bps,
+            vault_balance,
+        )?,
+        None => {
+            require!(tip_bps == 0, ErrorCode::InvalidTip);
+            0
+        }
+    };
+    let split_balance = vault_balance
+        .checked_sub(tip_amount)
+        .ok_or(ErrorCode::MathUnderflow)?;
+
+    // So is the fee floor's top-up on rounds too small for the percentage fee to reach it
+    let fee_floor = fee_floor_top_up(accounts, split_balance)?;
+    let split_balance = split_balance - fee_floor;
+
+    let (distributed, held_as_unclaimed) = distribute_to_recipients(
+        accounts,
+        remaining_accounts,
+        recipient_wallets,
+        0..recipients_len,
+        split_balance,
+    )?;
+
+    complete_split(
+        accounts,
+        remaining_accounts,
+        split_balance,
+        distributed,
+        held_as_unclaimed,
+        fee_floor,
+    )
+}
+
+/// Builds one execute_split_multi group as ExecuteSplit, applying the checks Anchor runs
+/// on ExecuteSplit's split_config, vault, mint and fee_tiers constraints
+fn load_multi_group<'info>(
+    shared: &ExecuteSplitMulti<'info>,
+    config_info: &'info AccountInfo<'info>,
+    vault_info: &'info AccountInfo<'info>,
+    mint_info: &'info AccountInfo<'info>,
+    fee_tiers_info: &'info AccountInfo<'info>,
+) -> Result<ExecuteSplit<'info>> {
+    require!(
+        config_info.is_writable && vault_info.is_writable,
+        anchor_lang::error::ErrorCode::ConstraintMut
+    );
+    let split_config = Box::new(Account::<SplitConfig>::try_from(config_info)?);
+    require_canonical_config(&split_config)?;
+    require!(split_config.version == CURRENT_VERSION, ErrorCode::ConfigNeedsMigration);
+    require!(!split_config.paused, ErrorCode::ConfigPaused);
+    require!(
+        split_config.executor_allowlist.is_empty()
+            || split_config.executor_allowlist.contains(&shared.executor.key()),
+        ErrorCode::ExecutorNotAllowed
+    );
+
+    // One token program per call; a vault under the other one is skipped, not aborted on
+    require!(
+        vault_info.owner == shared.token_program.key,
+        anchor_lang::error::ErrorCode::ConstraintTokenTokenProgram
+    );
+    let vault = InterfaceAccount::<TokenAccount>::try_from(vault_info)?;
+    let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+    require!(
+        vault.key() == split_config.vault && mint.key() == split_config.mint,
+        anchor_lang::error::ErrorCode::ConstraintRaw
+    );
+    require_vault_not_frozen(&vault)?;
+    let (expected_fee_tiers, _) = Pubkey::find_program_address(
+        &[b"fee_tiers", mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        fee_tiers_info.key(),
+        expected_fee_tiers,
+        anchor_lang::error::ErrorCode::ConstraintSeeds
+    );
+
+    Ok(ExecuteSplit {
+        split_config,
+        vault,
+        mint,
+        executor: shared.executor.clone(),
+        admin_config: shared.admin_config.clone(),
+        fee_exempt_mints: shared.fee_exempt_mints.clone(),
+        protocol_fee_wallets: shared.protocol_fee_wallets.clone(),
+        token_program: shared.token_program.clone(),
+        associated_token_program: shared.associated_token_program.clone(),
+        system_program: shared.system_program.clone(),
+        protocol_ata: None,
+        fee_tiers: UncheckedAccount::try_from(fee_tiers_info),
+        audit_log: None,
+    })
+}
+
+/// Side-effect-free dry run of split_vault's fallible checks, so execute_split_multi can
+/// skip a config before it emits events or moves tokens
+fn check_multi_group(accounts: &ExecuteSplit, remaining_accounts: &[AccountInfo]) -> Result<()> {
+    let config = &accounts.split_config;
+    require!(!config.split_in_progress(), ErrorCode::ChunkedSplitInProgress);
+    // The batch carries no AuditLog accounts
+    require!(!config.audit_log_enabled, ErrorCode::AuditLogMissing);
+
+    let undistributed = undistributed_balance(config, accounts.vault.amount)?;
+    let vault_balance = releasable_balance(config, undistributed, Clock::get()?.unix_timestamp);
+    if !check_execute_bounds(config, vault_balance)? && config.pending_protocol_fee == 0 {
+        return Ok(());
+    }
+
+    require!(
+        remaining_accounts.len()
+            == config.recipients.len() + reserve_slots(config) + protocol_slots(accounts),
+        ErrorCode::RecipientATACountMismatch
+    );
+    require_unique_accounts(remaining_accounts)
+}
+
+/// Numeric code of a caught error, as reported in MultiSplitResult
+fn error_code_of(error: &Error) -> u32 {
+    match error {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(error) => match error.program_error {
+            ProgramError::Custom(code) => code,
+            _ => u32::MAX,
+        },
+    }
+}
+
+/// Rejects remaining_accounts that repeat a key, so one account can't fill two slots
+fn require_unique_accounts(accounts: &[AccountInfo]) -> Result<()> {
+    for (i, account) in accounts.iter().enumerate() {
+        require!(
+            accounts[i + 1..].iter().all(|other| other.key != account.key),
+            ErrorCode::DuplicateAccount
+        );
+    }
+    Ok(())
+}
+
+/// Returns false when execution should be a no-op (below MIN_SPLIT_BALANCE or
+/// min_execute_amount), errors when the balance is above max_execute_amount,
+/// the config awaits its release authority or its execute cooldown is running
+/// Amounts are base units, so MIN_SPLIT_BALANCE is 100 whole tokens on a
+/// 0-decimal mint and a negligible fraction on a 9-decimal one
+fn check_execute_bounds(config: &SplitConfig, vault_balance: u64) -> Result<bool> {
+    if vault_balance < MIN_SPLIT_BALANCE || vault_balance < config.min_execute_amount {
+        return Ok(false);
+    }
+
+    // Escrow configs wait for the arbiter's release signal
+    require!(
+        config.release_authority.is_none() || config.released,
+        ErrorCode::NotReleased
+    );
+
+    // Oversized balances need manual review (0 = no cap)
+    require!(
+        config.max_execute_amount == 0 || vault_balance <= config.max_execute_amount,
+        ErrorCode::AboveMaxExecuteAmount
+    );
+
+    // Rate limit against keeper spam; no-op calls above never reach it (0 = none)
+    let next_allowed = config.last_execution_ts
+        .saturating_add(config.min_execute_interval_seconds as i64);
+    require!(
+        config.min_execute_interval_seconds == 0 || Clock::get()?.unix_timestamp >= next_allowed,
+        ErrorCode::ExecuteCooldown
+    );
+
+    Ok(true)
+}
+
+/// Pays recipients[range] their share of `balance`, holding failed transfers as unclaimed
+/// `recipient_atas[i]` pairs with `recipients[range.start + i]`, as does `recipient_wallets[i]`
+/// when missing ATAs should be created
+/// Returns (distributed, held_as_unclaimed)
+fn distribute_to_recipients<'info>(
+    accounts: &mut ExecuteSplit<'info>,
+    recipient_atas: &'info [AccountInfo<'info>],
+    recipient_wallets: Option<&'info [AccountInfo<'info>]>,
+    range: std::ops::Range<usize>,
+    balance: u64,
+) -> Result<(u64, u64)> {
+    let mut distributed = 0u64;
+    let mut held_as_unclaimed = 0u64;
+
+    let config_key = accounts.split_config.key();
+
+    // Clone recipients to avoid borrow issues
+    let first = range.start;
+    let recipients = accounts.split_config.recipients[range].to_vec();
+
+    // Cliffs are judged at the round's start so every chunk of a range split agrees
+    let round_ts = accounts.split_config.last_stream_ts;
+    let redistribution = if accounts.split_config.cliff_redistribute {
+        cliff_redistribution(&accounts.split_config, round_ts)?
+    } else {
+        None
+    };
+
+    // The remainder recipient's amount depends on every other share, so it is
+    // computed once over the whole set (range splits share the round balance)
+    let remainder = match accounts.split_config.remainder_recipient_index {
+        Some(index) if (index as usize) < accounts.split_config.recipients.len() => {
+            let fee_exempt = accounts.fee_exempt_mints.is_exempt(&accounts.split_config.mint);
+            let amount = remainder_amount(
+                &accounts.split_config,
+                index as usize,
+                balance,
+                redistribution,
+                round_ts,
+                fee_exempt,
+            )?;
+            Some((index as usize, amount))
+        }
+        _ => None,
+    };
+
+    for (i, recipient) in recipients.iter().enumerate() {
+        let recipient_ata_info = recipient_atas
+            .get(i)
+            .ok_or(ErrorCode::RecipientATACountMismatch)?;
+
+        // Position must hold this recipient's canonical ATA - never pay a shuffled slot
+        require_recipient_destination(
+            recipient_ata_info,
+            recipient,
+            &accounts.mint.key(),
+            &accounts.token_program.key(),
+        )?;
+        require_not_vault(recipient_ata_info, &accounts.vault.key())?;
+        require_token_program(recipient_ata_info, &accounts.token_program.key())?;
+
+        // Pre-cliff shares either flow to vested recipients via redistribution or are held
+        let before_cliff = recipient.cliff_ts > round_ts;
+        if before_cliff && redistribution.is_some() {
+            continue;
+        }
+
+        let amount = match remainder {
+            Some((index, amount)) if index == first + i => amount,
+            _ => round_share(&accounts.split_config, first + i, balance, redistribution, round_ts)?,
+        };
+        if amount == 0 {
+            continue;
+        }
+
+        // Dust below min_payout is held too, so it accumulates until worth claiming
+        let sent = if let Some(reason) = push_hold_reason(&accounts.split_config, first + i, round_ts) {
+            Err(reason)
+        } else if amount < accounts.split_config.min_payout {
+            Err(HeldReason::BelowMinPayout)
+        } else {
+            if let Some(wallets) = recipient_wallets {
+                let wallet_info = wallets
+                    .get(i)
+                    .ok_or(ErrorCode::RecipientATACountMismatch)?;
+                create_recipient_ata_if_missing(accounts, recipient_ata_info, wallet_info, recipient)?;
+            }
+
+            // Attempt to send to recipient
+            validate_and_send_to_recipient(
+                recipient_ata_info,
+                recipient,
+                amount,
+                &accounts.split_config,
+                &accounts.mint,
+                &accounts.vault,
+                &accounts.token_program,
+            )
+        };
+
+        match sent {
+            Ok(()) => {
+                distributed = distributed.checked_add(amount)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+            Err(reason) => {
+                // Hold as unclaimed - STAYS IN VAULT
+                hold_as_unclaimed(&mut accounts.split_config, recipient.address, amount)?;
+
+                held_as_unclaimed = held_as_unclaimed.checked_add(amount)
+                    .ok_or(ErrorCode::MathOverflow)?;
+
+                emit!(RecipientPaymentHeld {
+                    schema_version: EVENT_SCHEMA_VERSION,
+                    config: config_key,
+                    recipient: recipient.address,
+                    amount,
+                    reason,
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+        }
+    }
+
+    Ok((distributed, held_as_unclaimed))
+}
+
+/// Remaining-account slots the reserve ATA takes (right before the protocol ATA)
+fn reserve_slots(config: &SplitConfig) -> usize {
+    if config.reserve_bps > 0 { 1 } else { 0 }
+}
+
+/// Remaining-account slots the protocol ATA takes: none when it is passed as the
+/// named protocol_ata account, otherwise the LAST one
+fn protocol_slots(accounts: &ExecuteSplit) -> usize {
+    if accounts.protocol_ata.is_some() { 0 } else { 1 }
+}
+
+/// Pays the config's reserve cut to the reserve ATA (right before the protocol ATA slot)
+/// The reserve ATA is validated like recipient ATAs; a bad one fails the execution
+fn pay_reserve<'info>(
+    accounts: &ExecuteSplit<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    amount: u64,
+) -> Result<()> {
+    let reserve_ata_info = remaining_accounts
+        .len()
+        .checked_sub(1 + protocol_slots(accounts))
+        .and_then(|i| remaining_accounts.get(i))
+        .ok_or(ErrorCode::MissingReserveAccount)?;
+
+    let reserve = Recipient {
+        address: accounts.split_config.reserve,
+        percentage_bps: accounts.split_config.reserve_bps,
+        destination: RecipientDestination::Ata,
+        label: [0; 16],
+        frozen: false,
+        cliff_ts: 0,
+        accepted: false,
+        push_paused: false,
+    };
+    require_recipient_destination(
+        reserve_ata_info,
+        &reserve,
+        &accounts.mint.key(),
+        &accounts.token_program.key(),
+    )?;
+    require_not_vault(reserve_ata_info, &accounts.vault.key())?;
+
+    if amount > 0 {
+        validate_and_send_to_recipient(
+            reserve_ata_info,
+            &reserve,
+            amount,
+            &accounts.split_config,
+            &accounts.mint,
+            &accounts.vault,
+            &accounts.token_program,
+        )
+        .map_err(HeldReason::error)?;
+    }
+
+    emit!(ReservePaid {
+        schema_version: EVENT_SCHEMA_VERSION,
+        config: accounts.split_config.key(),
+        reserve: reserve.address,
+        reserve_bps: reserve.percentage_bps,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Pays an execution-time tip to the tip ATA (before the reserve and protocol ATAs)
+/// The tip ATA is validated like recipient ATAs; a bad one fails the execution
+fn pay_tip<'info>(
+    accounts: &ExecuteSplit<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    tip_recipient: Pubkey,
+    tip_bps: u16,
+    vault_balance: u64,
+) -> Result<u64> {
+    require!(tip_bps > 0 && tip_bps <= MAX_TIP_BPS, ErrorCode::InvalidTip);
+
+    let tip_ata_info = remaining_accounts
+        .len()
+        .checked_sub(1 + reserve_slots(&accounts.split_config) + protocol_slots(accounts))
+        .and_then(|i| remaining_accounts.get(i))
+        .ok_or(ErrorCode::MissingTipAccount)?;
+
+    let tip = Recipient {
+        address: tip_recipient,
+        percentage_bps: tip_bps,
+        destination: RecipientDestination::Ata,
+        label: [0; 16],
+        frozen: false,
+        cliff_ts: 0,
+        accepted: false,
+        push_paused: false,
+    };
+    require_recipient_destination(
+        tip_ata_info,
+        &tip,
+        &accounts.mint.key(),
+        &accounts.token_program.key(),
+    )?;
+    require_not_vault(tip_ata_info, &accounts.vault.key())?;
+
+    let tip_amount = share_amount(vault_balance, tip_bps)?;
+    if tip_amount > 0 {
+        validate_and_send_to_recipient(
+            tip_ata_info,
+            &tip,
+            tip_amount,
+            &accounts.split_config,
+            &accounts.mint,
+            &accounts.vault,
+            &accounts.token_program,
+        )
+        .map_err(HeldReason::error)?;
+    }
+
+    emit!(TipPaid {
+        schema_version: EVENT_SCHEMA_VERSION,
+        config: accounts.split_config.key(),
+        tip_recipient,
+        tip_bps,
+        amount: tip_amount,
+        executor: accounts.executor.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(tip_amount)
+}
+
+/// Creates a missing recipient ATA with the executor paying rent
+/// The ATA key is already checked against the derivation; existing, read-only or
+/// explicit (non-ATA) accounts are left alone (and held as unclaimed)
+fn create_recipient_ata_if_missing<'info>(
+    accounts: &ExecuteSplit<'info>,
+    recipient_ata_info: &AccountInfo<'info>,
+    wallet_info: &AccountInfo<'info>,
+    recipient: &Recipient,
+) -> Result<()> {
+    if recipient.destination != RecipientDestination::Ata
+        || !recipient_ata_info.data_is_empty()
+        || !recipient_ata_info.is_writable
+    {
+        return Ok(());
+    }
+    require!(wallet_info.key() == recipient.address, ErrorCode::RecipientATAWrongOwner);
+
+    associated_token::create(CpiContext::new(
+        accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: accounts.executor.to_account_info(),
+            associated_token: recipient_ata_info.clone(),
+            authority: wallet_info.clone(),
+            mint: accounts.mint.to_account_info(),
+            system_program: accounts.system_program.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+        },
+    ))
+}
+
+/// Accrues `amount` to the recipient's unclaimed entry (funds stay in the vault)
+fn hold_as_unclaimed(split_config: &mut SplitConfig, recipient: Pubkey, amount: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    if let Some(existing) = split_config.unclaimed_amounts.iter_mut()
+        .find(|u| u.recipient == recipient)
+    {
+        existing.amount = existing.amount.checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        existing.timestamp = now;
+    } else {
+        // Recipients, the protocol wallet and any future non-recipient payees share this cap
+        require!(
+            split_config.unclaimed_amounts.len() < MAX_UNCLAIMED,
+            ErrorCode::TooManyUnclaimedEntries
+        );
+
+        split_config.unclaimed_amounts.push(UnclaimedAmount {
+            recipient,
+            amount,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
+/// Pays the protocol fee (1% + dust, NOT unclaimed amounts), records lifetime
+/// totals and emits SplitExecuted. Protocol ATA is the LAST remaining account
+/// With strict_fee the fee is exactly floor(1%) and dust is held for the first active recipient
+/// `fee_floor` is the min_fee top-up already taken off before the split; it joins the fee
+fn complete_split<'info>(
+    accounts: &mut ExecuteSplit<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    total_amount: u64,
+    distributed: u64,
+    held_as_unclaimed: u64,
+    fee_floor: u64,
+) -> Result<ExecuteResult> {
+    // Protocol receives: 1% + dust only (NOT unclaimed amounts)
+    // Saturating: a round can never hand out more than it split, so zero is the floor
+    let residual = total_amount
+        .saturating_sub(distributed)
+        .saturating_sub(held_as_unclaimed);
+
+    // The reserve takes its fixed cut before the fee; rounding dust stays with the protocol
+    let reserve_amount = if accounts.split_config.reserve_bps > 0 {
+        let amount = share_amount(total_amount, accounts.split_config.reserve_bps)?.min(residual);
+        pay_reserve(accounts, remaining_accounts, amount)?;
+        amount
+    } else {
+        0
+    };
+    let residual = residual - reserve_amount;
+
+    // Fee-exempt mints skip the fee; a 100% config leaves nothing over, and a
+    // config still at 99% keeps the remainder in the vault for the next round
+    let fee_exempt = accounts.fee_exempt_mints.is_exempt(&accounts.split_config.mint);
+    require!(
+        fee_exempt || !shares_total_full(&accounts.split_config),
+        ErrorCode::FeeExemptionRevoked
+    );
+
+    // Volume tiers discount the 1% fee. Recipients still total 99%, so the discount
+    // stays in the vault and splits next round, like the unused 1% of a 99% config
+    // on a fee-exempt mint
+    let fee_tier = fee_tier(
+        load_fee_tiers(&accounts.fee_tiers)?.as_ref(),
+        accounts.split_config.total_distributed,
+    );
+    let fee_bps = match fee_tier {
+        _ if fee_exempt => 0,
+        Some((_, bps)) => bps,
+        None => PROTOCOL_FEE_BPS,
+    };
+
+    let (protocol_fee, dust) = if fee_exempt {
+        (0, 0)
+    } else {
+        let full_fee = share_amount(total_amount, PROTOCOL_FEE_BPS)?.min(residual);
+        let discount = full_fee - share_amount(total_amount, fee_bps)?.min(full_fee);
+        if accounts.split_config.strict_fee {
+            (full_fee - discount, residual - full_fee)
+        } else {
+            (residual - discount, 0)
+        }
+    };
+
+    if dust > 0 {
+        let dust_recipient = accounts.split_config.recipients.iter()
+            .find(|r| r.percentage_bps > 0)
+            .map(|r| r.address)
+            .ok_or(ErrorCode::InvalidRecipientCount)?;
+        hold_as_unclaimed(&mut accounts.split_config, dust_recipient, dust)?;
+    }
+
+    let protocol_fee = protocol_fee
+        .checked_add(fee_floor)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Reconcile against the real vault: the fee may only come out of funds
+    // not already earmarked for unclaimed entries
+    accounts.vault.reload()?;
+    let unearmarked = undistributed_balance(&accounts.split_config, accounts.vault.amount)?;
+    if protocol_fee > unearmarked {
+        msg!("Protocol fee capped at {} (unclaimed exceeds vault balance)", unearmarked);
+    }
+    let protocol_fee = protocol_fee.min(unearmarked);
+
+    if accounts.split_config.pull_only && accounts.split_config.hold_protocol_fee {
+        // Protocol pulls its fee (and anything deferred) via claim_unclaimed like any recipient
+        let owed = protocol_fee
+            .checked_add(accounts.split_config.pending_protocol_fee)
+            .ok_or(ErrorCode::MathOverflow)?;
+        if owed > 0 {
+            let protocol_wallet = accounts.protocol_fee_wallets.wallet_for(&accounts.split_config.mint);
+            hold_as_unclaimed(&mut accounts.split_config, protocol_wallet, owed)?;
+            accounts.split_config.pending_protocol_fee = 0;
+        }
+    } else {
+        settle_protocol_fee(accounts, remaining_accounts, protocol_fee)?;
+    }
+
+    // Lifetime counter: re-deposited high-supply tokens can push it past u64::MAX,
+    // which must not block distribution
+    let split_config = &mut accounts.split_config;
+    // Whatever stays unearmarked (streamed or below-minimum remainder) is the
+    // baseline the next execution measures new deposits against
+    split_config.last_seen_balance = unearmarked - protocol_fee;
+    split_config.total_distributed = split_config.total_distributed.saturating_add(distributed);
+    // A release covers one distribution; the next milestone needs a new signal
+    split_config.released = false;
+    split_config.execution_nonce = split_config.execution_nonce
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    split_config.last_execution_ts = Clock::get()?.unix_timestamp;
+    record_audit_entry(accounts, total_amount, protocol_fee)?;
+    pay_executor_tip(accounts)?;
+    let split_config = &accounts.split_config;
+
+    emit!(SplitExecuted {
+        schema_version: EVENT_SCHEMA_VERSION,
+        config: split_config.key(),
+        vault: accounts.vault.key(),
+        total_amount,
+        recipients_distributed: distributed,
+        protocol_fee,
+        held_count: held_as_unclaimed,
+        dust,
+        executor: accounts.executor.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+        fee_tier: fee_tier.map(|(index, _)| index),
+        fee_bps,
+    });
+
+    // Shares are raw units; interest scales every balance alike, so only the
+    // UI value of the round is reported for accounting
+    let now = Clock::get()?.unix_timestamp;
+    if let Some((ui_amount, rate_bps)) = interest_bearing_ui_amount(&accounts.mint, total_amount, now)? {
+        emit!(InterestBearingSplit {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: accounts.split_config.key(),
+            total_amount,
+            ui_amount,
+            rate_bps,
+            timestamp: now,
+        });
+    }
+
+    // A due queued set takes over once this round has paid out the current one
+    let undistributed = accounts.split_config.last_seen_balance;
+    promote_pending_recipients(&mut accounts.split_config, undistributed, now)?;
+
+    Ok(ExecuteResult {
+        distributed,
+        protocol_fee,
+        held: held_as_unclaimed,
+    })
+}
+
+/// Transfers the protocol fee to the protocol ATA (named protocol_ata, else LAST remaining account)
+/// Returns false without transferring if the ATA doesn't exist yet (graceful degradation)
+fn pay_protocol_fee<'info>(
+    accounts: &ExecuteSplit<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    protocol_fee: u64,
+) -> Result<bool> {
+    // 1. Derive expected protocol ATA for the mint's protocol wallet (Token-2022 compatible)
+    let protocol_wallet = accounts.protocol_fee_wallets.wallet_for(&accounts.mint.key());
+    let expected_protocol_ata = get_associated_token_address_with_program_id(
+        &protocol_wallet,
+        &accounts.mint.key(),
+        &accounts.token_program.key()  // Uses actual token program (Token or Token-2022)
+    );
+
+    // 2. Get protocol ATA: the named account, or the LAST remaining account
+    let protocol_ata_info = match &accounts.protocol_ata {
+        Some(protocol_ata) => protocol_ata.to_account_info(),
+        None => remaining_accounts
+            .last()
+            .cloned()
+            .ok_or(ErrorCode::MissingProtocolAccount)?,
+    };
+
+    // 3. Validate address matches expected derivation
+    require!(
+        protocol_ata_info.key() == expected_protocol_ata,
+        ErrorCode::InvalidProtocolFeeRecipient
+    );
+
+    // 4. Validate account is writable and not the vault itself
+    require!(
+        protocol_ata_info.is_writable,
+        ErrorCode::InvalidProtocolFeeRecipient
+    );
+    require_not_vault(&protocol_ata_info, &accounts.vault.key())?;
+
+    // 5. If protocol ATA doesn't exist, skip protocol fee (graceful degradation)
+    if protocol_ata_info.data_is_empty() {
+        // Protocol ATA doesn't exist yet - the caller defers the fee in the vault and
+        // emits ProtocolFeeDeferred, the structured signal for the protocol's accounting.
+        // Protocol can create ATA later and re-execute split to claim fees
+        msg!("Protocol ATA doesn't exist, skipping protocol fee transfer");
+        return Ok(false);
+    }
+
+    // 6. Validate account is owned by token program (SPL Token or Token-2022)
+    let valid_owner = protocol_ata_info.owner == &token::ID
+        || protocol_ata_info.owner == &token_2022::ID;
+    require!(valid_owner, ErrorCode::InvalidProtocolFeeRecipient);
+
+    // 7. Deserialize and validate token account fields
+    let protocol_ata = TokenAccount::try_deserialize(&mut &protocol_ata_info.try_borrow_data()?[..])
+        .map_err(|_| ErrorCode::InvalidProtocolFeeRecipient)?;
+
+    require!(
+        protocol_ata.owner == protocol_wallet,
+        ErrorCode::InvalidProtocolFeeRecipient
+    );
+    require!(
+        protocol_ata.mint == accounts.mint.key(),
+        ErrorCode::InvalidProtocolFeeRecipient
+    );
+
+    // 8. Transfer protocol fee
+    transfer_from_vault(
+        &accounts.split_config,
+        &accounts.vault,
+        &accounts.mint,
+        protocol_ata_info,
+        &accounts.token_program,
+        protocol_fee,
+    )?;
+    Ok(true)
+}
+
+/// Pays the configured SOL tip from the executor reserve to the executor
+/// A reserve short of the tip pays nothing; the distribution itself never depends on it
+fn pay_executor_tip(accounts: &mut ExecuteSplit) -> Result<()> {
+    let tip = accounts.split_config.executor_tip_lamports;
+    if tip == 0 {
+        return Ok(());
+    }
+    if accounts.split_config.executor_reserve_lamports < tip {
+        msg!(
+            "Executor tip skipped: reserve {} below tip {}",
+            accounts.split_config.executor_reserve_lamports,
+            tip
+        );
+        return Ok(());
+    }
+
+    accounts.split_config.executor_reserve_lamports -= tip;
+    **accounts.split_config.to_account_info().try_borrow_mut_lamports()? -= tip;
+    **accounts.executor.to_account_info().try_borrow_mut_lamports()? += tip;
+
+    emit!(ExecutorTipPaid {
+        schema_version: EVENT_SCHEMA_VERSION,
+        config: accounts.split_config.key(),
+        executor: accounts.executor.key(),
+        lamports: tip,
+        reserve_lamports: accounts.split_config.executor_reserve_lamports,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Appends the round to the config's AuditLog when it opted in
+/// The log is mandatory then, so no executor can leave a distribution out of it
+fn record_audit_entry(accounts: &mut ExecuteSplit, total_amount: u64, protocol_fee: u64) -> Result<()> {
+    if !accounts.split_config.audit_log_enabled {
+        return Ok(());
+    }
+    let audit_log = accounts.audit_log.as_mut().ok_or(ErrorCode::AuditLogMissing)?;
+    audit_log.record(AuditEntry {
+        timestamp: Clock::get()?.unix_timestamp,
+        total_amount,
+        protocol_fee,
+    });
+    Ok(())
+}
+
+/// Pays this round's fee plus any pending_protocol_fee to the protocol ATA
+/// If the ATA is missing the total is recorded as pending instead, which keeps it
+/// out of undistributed_balance so it is never re-split to recipients
+fn settle_protocol_fee<'info>(
+    accounts: &mut ExecuteSplit<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    protocol_fee: u64,
+) -> Result<()> {
+    let owed = protocol_fee
+        .checked_add(accounts.split_config.pending_protocol_fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if owed == 0 {
+        return Ok(());
+    }
+
+    if pay_protocol_fee(accounts, remaining_accounts, owed)? {
+        accounts.split_config.pending_protocol_fee = 0;
+    } else {
+        accounts.split_config.pending_protocol_fee = owed;
+        emit!(ProtocolFeeDeferred {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: accounts.split_config.key(),
+            amount: protocol_fee,
+            total_pending: owed,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    Ok(())
+}
+
+/// Helper function to validate recipient ATA and send tokens
+/// Enhanced validation to provide better error messages for debugging
+fn validate_and_send_to_recipient<'info>(
+    recipient_ata_info: &'info AccountInfo<'info>,
+    recipient: &Recipient,
+    amount: u64,
+    split_config: &Account<'info, SplitConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> std::result::Result<(), HeldReason> {
+    let recipient_ata = validate_recipient_ata(recipient_ata_info, recipient, &mint.key())?;
+
+    // Transfer tokens
+    transfer_from_vault(
+        split_config,
+        vault,
+        mint,
+        recipient_ata.to_account_info(),
+        token_program,
+        amount,
+    )
+    .map_err(|_| HeldReason::TransferFailed)
+}
+
+/// Checks a recipient ATA exists, is a token account, and matches the recipient and mint
+/// Failures come back as a HeldReason so callers can record why without formatting errors
+fn validate_recipient_ata<'info>(
+    recipient_ata_info: &'info AccountInfo<'info>,
+    recipient: &Recipient,
+    mint: &Pubkey,
+) -> std::result::Result<InterfaceAccount<'info, TokenAccount>, HeldReason> {
+    // Validate account exists and has data
+    if recipient_ata_info.data_is_empty() {
+        return Err(HeldReason::AtaMissing);
+    }
+
+    // Validate account is owned by token program (SPL Token or Token-2022)
+    let valid_owner = recipient_ata_info.owner == &token::ID
+        || recipient_ata_info.owner == &token_2022::ID;
+    if !valid_owner {
+        return Err(HeldReason::InvalidOwnerProgram);
+    }
+
+    // Try to deserialize as token account
+    let recipient_ata = InterfaceAccount::<'info, TokenAccount>::try_from(recipient_ata_info)
+        .map_err(|_| HeldReason::InvalidAccount)?;
+
+    // Verify owner and mint match expected values
+    if recipient_ata.owner != destination_owner(recipient) {
+        return Err(HeldReason::WrongOwner);
+    }
+    if recipient_ata.mint != *mint {
+        return Err(HeldReason::WrongMint);
+    }
+
+    Ok(recipient_ata)
+}
+
+/// Spreads freed bps across recipients with a non-zero share, proportional to their
+/// current bps. Floor remainder is handed out one bps at a time in recipient order.
+fn redistribute_bps(recipients: &mut [Recipient], freed_bps: u16) -> Result<()> {
+    let active_total: u32 = recipients.iter().map(|r| r.percentage_bps as u32).sum();
+    require!(active_total > 0, ErrorCode::InvalidRecipientCount);
+
+    let mut assigned = 0u32;
+    for recipient in recipients.iter_mut().filter(|r| r.percentage_bps > 0) {
+        let extra = (freed_bps as u32)
+            .checked_mul(recipient.percentage_bps as u32)
+            .ok_or(ErrorCode::MathOverflow)?
+            / active_total;
+        recipient.percentage_bps = recipient.percentage_bps
+            .checked_add(extra as u16)
+            .ok_or(ErrorCode::MathOverflow)?;
+        assigned += extra;
+    }
+
+    let mut leftover = freed_bps as u32 - assigned;
+    for recipient in recipients.iter_mut().filter(|r| r.percentage_bps > 0) {
+        if leftover == 0 {
+            break;
+        }
+        recipient.percentage_bps += 1;
+        leftover -= 1;
+    }
+
+    Ok(())
+}
+
+/// The mint's FeeTiers table, or None while its PDA has not been initialized
+fn load_fee_tiers(fee_tiers: &AccountInfo) -> Result<Option<FeeTiers>> {
+    if fee_tiers.owner != &crate::ID || fee_tiers.data_is_empty() {
+        return Ok(None);
+    }
+    let data = fee_tiers.try_borrow_data()?;
+    Ok(Some(FeeTiers::try_deserialize(&mut &data[..])?))
+}
+
+/// Volume tier a config's lifetime `volume` has reached: (index, fee_bps) of the last
+/// tier whose min_volume it meets, or None (full fee) without a tier table
+fn fee_tier(fee_tiers: Option<&FeeTiers>, volume: u64) -> Option<(u8, u16)> {
+    fee_tiers?
+        .tiers
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(_, tier)| volume >= tier.min_volume)
+        .map(|(index, tier)| (index as u8, tier.fee_bps))
+}
+
+/// Amount to take off the top of a round so the protocol fee reaches the mint's min_fee
+/// The floor is capped at MAX_FEE_FLOOR_BPS of the round; fee-exempt mints and rounds
+/// whose tiered fee already covers it pay nothing extra
+fn fee_floor_top_up(accounts: &ExecuteSplit, balance: u64) -> Result<u64> {
+    let Some(fee_tiers) = load_fee_tiers(&accounts.fee_tiers)? else {
+        return Ok(0);
+    };
+    if fee_tiers.min_fee == 0 || accounts.fee_exempt_mints.is_exempt(&accounts.split_config.mint) {
+        return Ok(0);
+    }
+
+    let floor = fee_tiers.min_fee.min(share_amount(balance, MAX_FEE_FLOOR_BPS)?);
+    let fee_bps = fee_tier(Some(&fee_tiers), accounts.split_config.total_distributed)
+        .map_or(PROTOCOL_FEE_BPS, |(_, bps)| bps);
+    Ok(floor.saturating_sub(share_amount(balance, fee_bps)?))
+}
+
+/// Recipient's share of `balance` for the given bps (floor division)
+fn share_amount(balance: u64, bps: u16) -> Result<u64> {
+    scaled_share(balance, bps as u32, BPS_DENOMINATOR)
+}
+
+/// balance * parts / denominator, floored (u128 intermediate)
+fn scaled_share(balance: u64, parts: u32, denominator: u32) -> Result<u64> {
+    let amount = (balance as u128)
+        .checked_mul(parts as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(denominator as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .try_into()
+        .map_err(|_| ErrorCode::MathOverflow)?;
+    Ok(amount)
+}
+
+/// Recipient `index`'s cut of `balance` in the config's precision
+fn recipient_share_amount(config: &SplitConfig, index: usize, balance: u64) -> Result<u64> {
+    match config.precision {
+        Precision::Bps => share_amount(balance, config.recipients[index].percentage_bps),
+        Precision::Ppm => {
+            let ppm = *config.share_ppm.get(index).ok_or(ErrorCode::InvalidSplitTotal)?;
+            scaled_share(balance, ppm, PPM_DENOMINATOR)
+        }
+    }
+}
+
+/// Recipient `index`'s floored share of a round, after any cliff redistribution
+/// (0 for a pre-cliff recipient whose share is redistributed)
+fn round_share(
+    config: &SplitConfig,
+    index: usize,
+    balance: u64,
+    redistribution: Option<(u32, u32)>,
+    round_ts: i64,
+) -> Result<u64> {
+    // Scaling the share rather than the balance keeps every intermediate in range
+    // for balances up to u64::MAX
+    let amount = recipient_share_amount(config, index, balance)?;
+    match redistribution {
+        Some(_) if config.recipients[index].cliff_ts > round_ts => Ok(0),
+        Some((total_units, vested_units)) => scaled_share(amount, total_units, vested_units),
+        None => Ok(amount),
+    }
+}
+
+/// What the remainder recipient receives: the round minus every other recipient's
+/// floored share, the reserve cut and the floored 1% fee, so nothing is left as dust
+fn remainder_amount(
+    config: &SplitConfig,
+    remainder_index: usize,
+    balance: u64,
+    redistribution: Option<(u32, u32)>,
+    round_ts: i64,
+    fee_exempt: bool,
+) -> Result<u64> {
+    let mut owed = share_amount(balance, config.reserve_bps)?;
+    if !fee_exempt {
+        owed = owed.checked_add(share_amount(balance, PROTOCOL_FEE_BPS)?)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    for index in (0..config.recipients.len()).filter(|&i| i != remainder_index) {
+        owed = owed.checked_add(round_share(config, index, balance, redistribution, round_ts)?)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    Ok(balance.saturating_sub(owed))
+}
+
+/// Recipient `index`'s share in the config's precision units (bps or ppm)
+fn recipient_share_units(config: &SplitConfig, index: usize) -> Result<u32> {
+    match config.precision {
+        Precision::Bps => Ok(config.recipients[index].percentage_bps as u32),
+        Precision::Ppm => Ok(*config.share_ppm.get(index).ok_or(ErrorCode::InvalidSplitTotal)?),
+    }
+}
+
+/// Scale (total units, vested units) applied to vested recipients' shares when
+/// pre-cliff shares are redistributed, so the round still pays out the full
+/// recipient portion. None when no recipient has passed their cliff
+fn cliff_redistribution(config: &SplitConfig, round_ts: i64) -> Result<Option<(u32, u32)>> {
+    let mut total_units = 0u32;
+    let mut vested_units = 0u32;
+    for (index, recipient) in config.recipients.iter().enumerate() {
+        let units = recipient_share_units(config, index)?;
+        total_units += units;
+        if recipient.cliff_ts <= round_ts {
+            vested_units += units;
+        }
+    }
+    Ok((vested_units > 0).then_some((total_units, vested_units)))
+}
+
+/// Checks a loaded config's invariants in order and returns the first one violated
+fn first_config_violation(
+    config: &Account<SplitConfig>,
+    vault: &InterfaceAccount<TokenAccount>,
+    fee_exempt: bool,
+) -> Result<Option<ConfigViolation>> {
+    if config.version != CURRENT_VERSION {
+        return Ok(Some(ConfigViolation::StaleVersion));
+    }
+
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"split_config", config.authority.as_ref(), config.mint.as_ref()],
+        &crate::ID,
+    );
+    if config.key() != expected {
+        return Ok(Some(ConfigViolation::NonCanonicalAddress));
+    }
+    if config.bump != bump {
+        return Ok(Some(ConfigViolation::BumpMismatch));
+    }
+
+    if vault.key() != config.vault || vault.owner != config.key() {
+        return Ok(Some(ConfigViolation::VaultMismatch));
+    }
+    if vault.mint != config.mint {
+        return Ok(Some(ConfigViolation::VaultMintMismatch));
+    }
+
+    for (i, recipient) in config.recipients.iter().enumerate() {
+        if config.recipients[i + 1..].iter().any(|r| r.address == recipient.address) {
+            return Ok(Some(ConfigViolation::DuplicateRecipient));
+        }
+    }
+
+    // Renounced recipients sit at 0 with their share already redistributed,
+    // so the total holds even though individual shares may be zero
+    if config.precision == Precision::Ppm && config.share_ppm.len() != config.recipients.len() {
+        return Ok(Some(ConfigViolation::InvalidShareTotal));
+    }
+    let total = shares_total(config);
+    let required = required_share_total(config.precision, false, config.reserve_bps);
+    let full = required_share_total(config.precision, true, config.reserve_bps);
+    if total != required && !(fee_exempt && total == full) {
+        return Ok(Some(ConfigViolation::InvalidShareTotal));
+    }
+
+    // Deferred protocol fee is earmarked alongside unclaimed entries
+    let mut held = config.pending_protocol_fee;
+    for entry in &config.unclaimed_amounts {
+        held = held.checked_add(entry.amount).ok_or(ErrorCode::MathOverflow)?;
+    }
+    if held > vault.amount {
+        return Ok(Some(ConfigViolation::UnclaimedExceedsVault));
+    }
+
+    Ok(None)
+}
+
+/// Validates every share is non-zero and the total is 99% less the reserve in `precision`
+/// (100% less the reserve for fee-exempt mints, which pay no protocol fee).
+/// In ppm mode `share_ppm` pairs with recipients and `percentage_bps` is set
+/// from it (floored, informational only)
+fn validate_shares(
+    precision: Precision,
+    recipients: &mut [Recipient],
+    share_ppm: &[u32],
+    fee_exempt: bool,
+    reserve_bps: u16,
+) -> Result<()> {
+    let required = required_share_total(precision, fee_exempt, reserve_bps);
+    match precision {
+        Precision::Bps => {
+            require!(share_ppm.is_empty(), ErrorCode::InvalidSplitTotal);
+            require!(recipients.iter().all(|r| r.percentage_bps > 0), ErrorCode::ZeroPercentage);
+
+            let sum: u64 = recipients.iter().map(|r| r.percentage_bps as u64).sum();
+            require!(sum == required, ErrorCode::InvalidSplitTotal);
+        }
+        Precision::Ppm => {
+            require!(share_ppm.len() == recipients.len(), ErrorCode::InvalidSplitTotal);
+            require!(share_ppm.iter().all(|&ppm| ppm > 0), ErrorCode::ZeroPercentage);
+
+            let sum: u64 = share_ppm.iter().map(|&ppm| ppm as u64).sum();
+            require!(sum == required, ErrorCode::InvalidSplitTotal);
+
+            for (recipient, &ppm) in recipients.iter_mut().zip(share_ppm) {
+                recipient.percentage_bps = (ppm / (PPM_DENOMINATOR / BPS_DENOMINATOR)) as u16;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Share total recipients must reach in `precision`: 100% less the reserve,
+/// less the protocol fee unless the mint is fee-exempt
+fn required_share_total(precision: Precision, fee_exempt: bool, reserve_bps: u16) -> u64 {
+    let base = if fee_exempt { BPS_DENOMINATOR } else { REQUIRED_SPLIT_TOTAL as u32 };
+    let bps = (base - reserve_bps as u32) as u64;
+    match precision {
+        Precision::Bps => bps,
+        Precision::Ppm => bps * (PPM_DENOMINATOR / BPS_DENOMINATOR) as u64,
+    }
+}
+
+/// Scales percentages out of BPS_DENOMINATOR down to `budget` bps in place
+/// Floors each share, then hands the leftover bps to the largest remainders
+/// (earlier recipients first on ties) so the result totals exactly `budget`
+fn scale_percentages(recipients: &mut [Recipient], budget: u16) -> Result<()> {
+    let percentages: Vec<u32> = recipients.iter().map(|r| r.percentage_bps as u32).collect();
+    let total: u32 = percentages.iter().sum();
+    require!(total == BPS_DENOMINATOR, ErrorCode::InvalidPercentTotal);
+    apportion_weights(recipients, &percentages, budget)
+}
+
+/// Sets each recipient's bps to its weight's share of `budget` (weights[i] pairs with
+/// recipients[i]), by the same largest-remainder rule as scale_percentages
+fn apportion_weights(recipients: &mut [Recipient], weights: &[u32], budget: u16) -> Result<()> {
+    let total: u64 = weights.iter().map(|&weight| weight as u64).sum();
+    require!(total > 0, ErrorCode::InvalidWeights);
+
+    let mut remainders = Vec::with_capacity(recipients.len());
+    let mut scaled_total = 0u32;
+    for (i, (recipient, &weight)) in recipients.iter_mut().zip(weights).enumerate() {
+        let scaled = weight as u64 * budget as u64;
+        recipient.percentage_bps = (scaled / total) as u16;
+        scaled_total += recipient.percentage_bps as u32;
+        remainders.push((scaled % total, i));
+    }
+
+    // Stable sort keeps index order among equal remainders
+    remainders.sort_by_key(|&(remainder, _)| std::cmp::Reverse(remainder));
+    let leftover = budget as u32 - scaled_total;
+    for &(_, i) in remainders.iter().take(leftover as usize) {
+        recipients[i].percentage_bps += 1;
+    }
+
+    require!(
+        recipients.iter().all(|r| r.percentage_bps > 0),
+        ErrorCode::ZeroScaledShare
+    );
+    Ok(())
+}
+
+/// Sum of the config's recipient shares in its precision
+fn shares_total(config: &SplitConfig) -> u64 {
+    match config.precision {
+        Precision::Bps => config.recipients.iter().map(|r| r.percentage_bps as u64).sum(),
+        Precision::Ppm => config.share_ppm.iter().map(|&ppm| ppm as u64).sum(),
+    }
+}
+
+/// Whether the config's shares leave no room for the protocol fee
+/// (only valid while its mint is fee-exempt)
+fn shares_total_full(config: &SplitConfig) -> bool {
+    shares_total(config) == required_share_total(config.precision, true, config.reserve_bps)
+}
+
+/// Why recipient `index`'s share would be held rather than pushed in a round at `round_ts`
+/// Pull-only configs never push - every share waits in the vault for a claim
+fn push_hold_reason(config: &SplitConfig, index: usize, round_ts: i64) -> Option<HeldReason> {
+    let recipient = &config.recipients[index];
+    if recipient.frozen {
+        Some(HeldReason::Frozen)
+    } else if recipient.push_paused {
+        Some(HeldReason::PushPaused)
+    } else if recipient.cliff_ts > round_ts {
+        Some(HeldReason::BeforeCliff)
+    } else if config.require_acceptance && !recipient.accepted {
+        Some(HeldReason::NotAccepted)
+    } else if config.pull_only {
+        Some(HeldReason::PullOnly)
+    } else {
+        None
+    }
+}
+
+/// Records a claim in a fresh ClaimReceipt and advances the config's receipt nonce
+/// Mandatory when the config enabled claim_receipts, so its receipt history has no gaps
+fn write_claim_receipt(accounts: &mut ClaimUnclaimed, bump: Option<u8>, amount: u64) -> Result<()> {
+    let Some(receipt) = accounts.claim_receipt.as_mut() else {
+        require!(!accounts.split_config.claim_receipts, ErrorCode::ClaimReceiptMissing);
+        return Ok(());
+    };
+    let config = &mut accounts.split_config;
+    receipt.recipient = accounts.recipient.key();
+    receipt.config = config.key();
+    receipt.mint = config.mint;
+    receipt.amount = amount;
+    receipt.timestamp = Clock::get()?.unix_timestamp;
+    receipt.nonce = config.claim_receipt_nonce;
+    receipt.bump = bump.ok_or(ErrorCode::ClaimReceiptMissing)?;
+    config.claim_receipt_nonce = config.claim_receipt_nonce
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    Ok(())
+}
+
+/// Removes and returns the claimer's unclaimed entry
+fn take_unclaimed(split_config: &mut SplitConfig, claimer: Pubkey) -> Result<UnclaimedAmount> {
+    let index = split_config.unclaimed_amounts.iter()
+        .position(|u| u.recipient == claimer)
+        .ok_or(ErrorCode::NothingToClaim)?;
+    require_claim_delay_elapsed(split_config, &split_config.unclaimed_amounts[index])?;
+
+    Ok(split_config.unclaimed_amounts.remove(index))
+}
+
+/// Removes every unclaimed entry for `claimer` and returns their summed amount
+fn take_all_unclaimed(split_config: &mut SplitConfig, claimer: Pubkey) -> Result<u64> {
+    let mut total = 0u64;
+    for entry in split_config.unclaimed_amounts.iter().filter(|u| u.recipient == claimer) {
+        require_claim_delay_elapsed(split_config, entry)?;
+        total = total.checked_add(entry.amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    require!(total > 0, ErrorCode::NothingToClaim);
+
+    split_config.unclaimed_amounts.retain(|u| u.recipient != claimer);
+    Ok(total)
+}
+
+/// Decrements `claimer`'s first unclaimed entry by `amount`, removing it once empty
+fn take_partial_unclaimed(split_config: &mut SplitConfig, claimer: Pubkey, amount: u64) -> Result<()> {
+    let index = split_config.unclaimed_amounts.iter()
+        .position(|u| u.recipient == claimer)
+        .ok_or(ErrorCode::NothingToClaim)?;
+    require_claim_delay_elapsed(split_config, &split_config.unclaimed_amounts[index])?;
+
+    let entry = &mut split_config.unclaimed_amounts[index];
+    require!(amount > 0 && amount <= entry.amount, ErrorCode::InvalidClaimAmount);
+    entry.amount -= amount;
+
+    if entry.amount == 0 {
+        split_config.unclaimed_amounts.remove(index);
+    }
+    Ok(())
+}
+
+/// Held entries become claimable claim_delay_seconds after they were (last) held
+fn require_claim_delay_elapsed(split_config: &SplitConfig, entry: &UnclaimedAmount) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now.saturating_sub(entry.timestamp) >= split_config.claim_delay_seconds as i64,
+        ErrorCode::ClaimTooEarly
+    );
+    Ok(())
+}
+
+/// Portion of the fresh balance this execution may split
+/// Streaming configs release `stream_rate` per second since the last execution
+fn releasable_balance(config: &SplitConfig, fresh_balance: u64, now: i64) -> u64 {
+    if config.stream_rate == 0 {
+        return fresh_balance;
+    }
+    let elapsed = now.saturating_sub(config.last_stream_ts).max(0) as u64;
+    config.stream_rate.saturating_mul(elapsed).min(fresh_balance)
+}
+
+/// Emits BalanceIncrease when the undistributed balance grew since the last
+/// execution - a coarse deposit signal for plain transfers into the vault.
+/// Claims move vault and unclaimed entries together, so they never register
+fn record_balance_increase(config: &mut Account<SplitConfig>, undistributed: u64) -> Result<()> {
+    if undistributed > config.last_seen_balance {
+        emit!(BalanceIncrease {
+            schema_version: EVENT_SCHEMA_VERSION,
+            config: config.key(),
+            delta: undistributed - config.last_seen_balance,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    config.last_seen_balance = undistributed;
+    Ok(())
+}
+
+/// Vault funds not yet attributed to anyone (balance minus held unclaimed entries
+/// and any deferred protocol fee)
+fn undistributed_balance(config: &SplitConfig, vault_amount: u64) -> Result<u64> {
+    let mut held = config.pending_protocol_fee;
+    for entry in &config.unclaimed_amounts {
+        held = held.checked_add(entry.amount).ok_or(ErrorCode::MathOverflow)?;
+    }
+    // Saturating: an over-committed config has nothing left to split, not an error
+    Ok(vault_amount.saturating_sub(held))
+}
+
+/// Transfers tokens out of the vault, signed by the split_config PDA
+fn transfer_from_vault<'info>(
+    split_config: &Account<'info, SplitConfig>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    let seeds = &[
+        b"split_config".as_ref(),
+        split_config.authority.as_ref(),
+        split_config.mint.as_ref(),
+        &[split_config.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: vault.to_account_info(),
+        mint: mint.to_account_info(),
+        to,
+        authority: split_config.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)
+}
+
+// Account Structs
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey, recipients: Vec<Recipient>)]
+pub struct CreateSplitConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = SPLIT_CONFIG_SIZE,
+        seeds = [b"split_config", authority.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub split_config: Account<'info, SplitConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = split_config,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [b"fee_exempt_mints"],
+        bump = fee_exempt_mints.bump
+    )]
+    pub fee_exempt_mints: Box<Account<'info, FeeExemptMints>>,
+
+    /// CHECK: Receives SOL creation fee; address constrained to protocol wallet
+    #[account(mut, address = PROTOCOL_WALLET @ ErrorCode::InvalidProtocolFeeRecipient)]
+    pub protocol_wallet: UncheckedAccount<'info>,
+
+    /// Only required when a token-denominated creation fee is set
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Only required when a token-denominated creation fee is set
+    #[account(mut)]
+    pub protocol_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The authority's index, when they opted into one; the new config is appended
+    #[account(
+        mut,
+        seeds = [b"authority_index", authority.key().as_ref()],
+        bump = authority_index.bump
+    )]
+    pub authority_index: Option<Box<Account<'info, AuthorityIndex>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSplit<'info> {
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration,
+        constraint = !split_config.paused @ ErrorCode::ConfigPaused
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(mut)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Anyone can execute (permissionless) unless the config has an allowlist
+    /// Always signs so SplitExecuted.executor is attributable; pays rent for created ATAs
+    #[account(
+        mut,
+        constraint = split_config.executor_allowlist.is_empty()
+            || split_config.executor_allowlist.contains(&executor.key())
+            @ ErrorCode::ExecutorNotAllowed
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    #[account(
+        seeds = [b"fee_exempt_mints"],
+        bump = fee_exempt_mints.bump
+    )]
+    pub fee_exempt_mints: Box<Account<'info, FeeExemptMints>>,
+
+    #[account(
+        seeds = [b"protocol_fee_wallets"],
+        bump = protocol_fee_wallets.bump
+    )]
+    pub protocol_fee_wallets: Box<Account<'info, ProtocolFeeWallets>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Protocol wallet's ATA for mint (may not exist yet - the fee is then deferred)
+    /// When passed, the protocol ATA is no longer the LAST remaining account
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &protocol_fee_wallets.wallet_for(&mint.key()),
+            &mint.key(),
+            &token_program.key(),
+        ) @ ErrorCode::InvalidProtocolFeeRecipient
+    )]
+    pub protocol_ata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Mint's FeeTiers PDA (volume tiers and fee floor), read by load_fee_tiers.
+    /// Always required so an executor can't drop a discount or the floor; while it is
+    /// uninitialized the full 1% fee applies with no floor
+    #[account(
+        seeds = [b"fee_tiers", mint.key().as_ref()],
+        bump
+    )]
+    pub fee_tiers: UncheckedAccount<'info>,
+
+    /// Config's AuditLog; required once the config opted in with initialize_audit_log
+    #[account(
+        mut,
+        seeds = [b"audit_log", split_config.key().as_ref()],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Option<Box<Account<'info, AuditLog>>>,
+}
+
+/// Shared accounts for execute_split_multi; each config's accounts follow in remaining_accounts
+#[derive(Accounts)]
+pub struct ExecuteSplitMulti<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    #[account(
+        seeds = [b"fee_exempt_mints"],
+        bump = fee_exempt_mints.bump
+    )]
+    pub fee_exempt_mints: Box<Account<'info, FeeExemptMints>>,
+
+    #[account(
+        seeds = [b"protocol_fee_wallets"],
+        bump = protocol_fee_wallets.bump
+    )]
+    pub protocol_fee_wallets: Box<Account<'info, ProtocolFeeWallets>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Recipient signers go in remaining_accounts
+#[derive(Accounts)]
+pub struct RecipientTakeover<'info> {
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptRecipient<'info> {
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    /// CHECK: must be the recipient's configured destination; validated in the instruction
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = *vault.to_account_info().owner == token_program.key() @ ErrorCode::RecipientATAInvalidOwner,
+        address = split_config.vault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimUnclaimed<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration,
+        constraint = !split_config.is_frozen(&recipient.key()) @ ErrorCode::RecipientIsFrozen,
+        constraint = !split_config.before_cliff(&recipient.key(), Clock::get()?.unix_timestamp) @ ErrorCode::RecipientBeforeCliff,
+        constraint = !split_config.awaiting_acceptance(&recipient.key()) @ ErrorCode::RecipientNotAccepted
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = split_config.mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(
+        init,
+        payer = recipient,
+        space = CLAIM_RECEIPT_SIZE,
+        seeds = [
+            b"claim_receipt",
+            split_config.key().as_ref(),
+            recipient.key().as_ref(),
+            &split_config.claim_receipt_nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub claim_receipt: Option<Box<Account<'info, ClaimReceipt>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimUnclaimedMany<'info> {
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(mut)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimUnclaimedSwap<'info> {
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration,
+        constraint = !split_config.is_frozen(&recipient.key()) @ ErrorCode::RecipientIsFrozen,
+        constraint = !split_config.before_cliff(&recipient.key(), Clock::get()?.unix_timestamp) @ ErrorCode::RecipientBeforeCliff,
+        constraint = !split_config.awaiting_acceptance(&recipient.key()) @ ErrorCode::RecipientNotAccepted
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(mut)]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Swap input: the claimed amount lands here before the swap spends it
+    #[account(
+        mut,
+        associated_token::mint = split_config.mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = output_mint.key() != split_config.mint @ ErrorCode::InvalidSwapProgram
+    )]
+    pub output_mint: InterfaceAccount<'info, Mint>,
+
+    /// Swap output, measured before and after the swap for the min_out check
+    #[account(
+        mut,
+        associated_token::mint = output_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = output_token_program,
+    )]
+    pub recipient_output_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Any executable program except cascadepay itself; it only ever gets the
+    /// recipient's signature, and the route accounts come from remaining_accounts
+    pub swap_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub output_token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_recipients: Vec<Recipient>)]
+pub struct UpdateSplitConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"split_config", authority.key().as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    #[account(
+        seeds = [b"fee_exempt_mints"],
+        bump = fee_exempt_mints.bump
+    )]
+    pub fee_exempt_mints: Box<Account<'info, FeeExemptMints>>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateSplitConfig<'info> {
+    /// CHECK: Discriminator checked in handler; older layouts can't be deserialized until resized
+    #[account(mut, owner = crate::ID)]
+    pub split_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RenounceShare<'info> {
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimUnclaimedDelegated<'info> {
+    pub delegate: Signer<'info>,
+
+    /// CHECK: Only used as a key - the delegate constraint ties it to `delegate`,
+    /// and recipient_ata must be its canonical ATA
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration,
+        constraint = split_config.claim_delegate(&recipient.key()) == Some(delegate.key()) @ ErrorCode::NotClaimDelegate,
+        constraint = !split_config.is_frozen(&recipient.key()) @ ErrorCode::RecipientIsFrozen,
+        constraint = !split_config.before_cliff(&recipient.key(), Clock::get()?.unix_timestamp) @ ErrorCode::RecipientBeforeCliff,
+        constraint = !split_config.awaiting_acceptance(&recipient.key()) @ ErrorCode::RecipientNotAccepted
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = split_config.mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimUnclaimedPda<'info> {
+    /// CHECK: Only used as a key - the handler re-derives it from the given seeds,
+    /// and recipient_ata must be its canonical ATA
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration,
+        constraint = !split_config.is_frozen(&recipient.key()) @ ErrorCode::RecipientIsFrozen,
+        constraint = !split_config.before_cliff(&recipient.key(), Clock::get()?.unix_timestamp) @ ErrorCode::RecipientBeforeCliff,
+        constraint = !split_config.awaiting_acceptance(&recipient.key()) @ ErrorCode::RecipientNotAccepted
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = split_config.mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = PROTOCOL_CONFIG_SIZE,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Cascadepay>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAdminConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ADMIN_CONFIG_SIZE,
+        seeds = [b"admin_config"],
+        bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::Cascadepay>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAdminConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SweepProtocolFee<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = protocol_ata.key() == get_associated_token_address_with_program_id(
+            &protocol_fee_wallets.wallet_for(&mint.key()),
+            &mint.key(),
+            &token_program.key()
+        ) @ ErrorCode::InvalidProtocolFeeRecipient
+    )]
+    pub protocol_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"protocol_fee_wallets"],
+        bump = protocol_fee_wallets.bump
+    )]
+    pub protocol_fee_wallets: Box<Account<'info, ProtocolFeeWallets>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct GetEffectiveShare<'info> {
+    #[account(
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct GetClaimable<'info> {
+    #[account(
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct PreviewKeeperReward<'info> {
+    #[account(
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct GetAuditLog<'info> {
+    #[account(
+        seeds = [b"audit_log", audit_log.config.as_ref()],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+}
+
+/// No seeds or version constraint: verify_config reports those as violations
+#[derive(Accounts)]
+pub struct VerifyConfig<'info> {
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"fee_exempt_mints"],
+        bump = fee_exempt_mints.bump
+    )]
+    pub fee_exempt_mints: Box<Account<'info, FeeExemptMints>>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimUnclaimedNative<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration,
+        constraint = !split_config.is_frozen(&recipient.key()) @ ErrorCode::RecipientIsFrozen,
+        constraint = !split_config.before_cliff(&recipient.key(), Clock::get()?.unix_timestamp) @ ErrorCode::RecipientBeforeCliff,
+        constraint = !split_config.awaiting_acceptance(&recipient.key()) @ ErrorCode::RecipientNotAccepted
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = NATIVE_MINT @ ErrorCode::NotNativeMint,
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = split_config.mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump,
+        constraint = !admin_config.global_paused @ ErrorCode::GloballyPaused
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct OpsAction<'info> {
+    pub ops_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = ops_authority @ ErrorCode::Unauthorized,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSplitSettings<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"split_config", authority.key().as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct FundExecutorReserve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"split_config", authority.key().as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAuditLog<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"split_config", authority.key().as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AUDIT_LOG_SIZE,
+        seeds = [b"audit_log", split_config.key().as_ref()],
+        bump
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAuthorityIndex<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AUTHORITY_INDEX_SIZE,
+        seeds = [b"authority_index", authority.key().as_ref()],
+        bump
+    )]
+    pub authority_index: Box<Account<'info, AuthorityIndex>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SignalRelease<'info> {
+    pub release_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration,
+        constraint = split_config.release_authority == Some(release_authority.key()) @ ErrorCode::Unauthorized
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct ValidateRecipients<'info> {
+    #[account(
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+#[derive(Accounts)]
+pub struct EnsureRecipientAtas<'info> {
+    /// Pays rent for every ATA created
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"protocol_fee_wallets"],
+        bump = protocol_fee_wallets.bump
+    )]
+    pub protocol_fee_wallets: Box<Account<'info, ProtocolFeeWallets>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CompactUnclaimed<'info> {
+    #[account(
+        mut,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct FundVault<'info> {
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = funder,
+        token::token_program = token_program,
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct SeedUnclaimed<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [b"split_config", authority.key().as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ForceCloseSplitConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        has_one = authority,
+        seeds = [b"split_config", authority.key().as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = authority,
+        associated_token::token_program = token_program,
+    )]
+    pub authority_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// The authority's index, when they opted into one; the config is removed from it
+    #[account(
+        mut,
+        seeds = [b"authority_index", authority.key().as_ref()],
+        bump = authority_index.bump
+    )]
+    pub authority_index: Option<Box<Account<'info, AuthorityIndex>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct RescueLamports<'info> {
+    #[account(mut)]
+    pub ops_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = ops_authority @ ErrorCode::Unauthorized,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct RescueTokens<'info> {
+    pub ops_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = ops_authority @ ErrorCode::Unauthorized,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        constraint = stray_mint.key() != split_config.mint @ ErrorCode::CannotRescueConfigMint
+    )]
+    pub stray_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = stray_mint,
+        token::authority = split_config,
+        token::token_program = token_program,
+    )]
+    pub stray_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = stray_mint,
+        token::authority = ops_authority,
+        token::token_program = token_program,
+    )]
+    pub ops_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(destination: Pubkey)]
+pub struct EmergencyDrain<'info> {
+    pub ops_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = ops_authority @ ErrorCode::Unauthorized,
+        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration,
+        constraint = split_config.paused @ ErrorCode::ConfigNotPaused
+    )]
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = mint.key() == split_config.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = destination,
+        associated_token::token_program = token_program,
+    )]
+    pub destination_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeProtocolFeeWallets<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = PROTOCOL_FEE_WALLETS_SIZE,
+        seeds = [b"protocol_fee_wallets"],
+        bump
+    )]
+    pub protocol_fee_wallets: Account<'info, ProtocolFeeWallets>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolFeeWallets<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"protocol_fee_wallets"],
+        bump = protocol_fee_wallets.bump
+    )]
+    pub protocol_fee_wallets: Account<'info, ProtocolFeeWallets>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeFeeExemptMints<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FEE_EXEMPT_MINTS_SIZE,
+        seeds = [b"fee_exempt_mints"],
+        bump
+    )]
+    pub fee_exempt_mints: Account<'info, FeeExemptMints>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeeExemptMints<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_exempt_mints"],
+        bump = fee_exempt_mints.bump
+    )]
+    pub fee_exempt_mints: Account<'info, FeeExemptMints>,
+}
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct InitializeFeeTiers<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = FEE_TIERS_SIZE,
+        seeds = [b"fee_tiers", mint.as_ref()],
+        bump
+    )]
+    pub fee_tiers: Account<'info, FeeTiers>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeeTiers<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        has_one = admin @ ErrorCode::Unauthorized,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_tiers", fee_tiers.mint.as_ref()],
+        bump = fee_tiers.bump
+    )]
+    pub fee_tiers: Account<'info, FeeTiers>,
+}
 
 #[derive(Accounts)]
-#[instruction(mint: Pubkey, recipients: Vec<Recipient>)]
-pub struct CreateSplitConfig<'info> {
+pub struct CloseSplitConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
     #[account(
-        init,
-        payer = authority,
-        space = SPLIT_CONFIG_SIZE,
-        seeds = [b"split_config", authority.key().as_ref(), mint.key().as_ref()],
-        bump
+        mut,
+        close = authority,
+        has_one = authority,
+        seeds = [b"split_config", authority.key().as_ref(), split_config.mint.as_ref()],
+        bump = split_config.bump,
+        constraint = split_config.version == CURRENT_VERSION @ ErrorCode::ConfigNeedsMigration
     )]
-    pub split_config: Account<'info, SplitConfig>,
+    pub split_config: Box<Account<'info, SplitConfig>>,
+
+    #[account(
+        mut,
+        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The authority's index, when they opted into one; the config is removed from it
+    #[account(
+        mut,
+        seeds = [b"authority_index", authority.key().as_ref()],
+        bump = authority_index.bump
+    )]
+    pub authority_index: Option<Box<Account<'info, AuthorityIndex>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Data Structures
+
+// Layout is append-only: new fields go after `bump` and must decode from zero
+// bytes as their default, so migrate_split_config can resize and re-serialize.
+#[account]
+pub struct SplitConfig {
+    pub version: u8,                            // 1 (for future migrations)
+    pub authority: Pubkey,                      // 32
+    pub mint: Pubkey,                           // 32
+    pub vault: Pubkey,                          // 32
+    pub recipients: Vec<Recipient>,             // 4 + (34 * n)
+    pub unclaimed_amounts: Vec<UnclaimedAmount>,// 4 + (48 * n)
+    pub bump: u8,                               // 1
+    pub total_distributed: u64,                 // 8 - lifetime amount paid to recipients (v2)
+    pub min_execute_amount: u64,                // 8 - below this execute is a no-op, 0 = none (v3)
+    pub max_execute_amount: u64,                // 8 - above this execute errors, 0 = no cap (v3)
+    pub split_cursor: u8,                       // 1 - next recipient index for chunked splits (v4)
+    pub round_balance: u64,                     // 8 - vault balance snapshotted for chunked split (v4)
+    pub round_distributed: u64,                 // 8 - paid so far in chunked split (v4)
+    pub round_held: u64,                        // 8 - held as unclaimed so far in chunked split (v4)
+    pub precision: Precision,                   // 1 - share unit, fixed at creation (v6)
+    pub share_ppm: Vec<u32>,                    // 4 + 4*20 - ppm shares, Ppm precision only (v6)
+    pub pull_only: bool,                        // 1 - execute accrues shares as unclaimed, never pushes (v7)
+    pub hold_protocol_fee: bool,                // 1 - in pull-only mode, also hold the protocol fee (v7)
+    pub metadata_uri: String,                   // 4 + 128 - URI of the governing agreement (v9)
+    pub executor_allowlist: Vec<Pubkey>,        // 4 + (32 * 5) - empty = permissionless (v10)
+    pub min_payout: u64,                        // 8 - shares below this are held, 0 = disabled (v11)
+    pub recipients_epoch: u32,                  // 4 - bumped on every recipient/share change (v12)
+    pub strict_fee: bool,                       // 1 - fee is exactly floor(1%), dust held for a recipient (v13)
+    pub release_authority: Option<Pubkey>,      // 33 - arbiter gating execution, None = ungated (v14)
+    pub released: bool,                         // 1 - arbiter approved the next distribution (v14)
+    pub stream_rate: u64,                       // 8 - base units released per second, 0 = whole vault (v16)
+    pub last_stream_ts: i64,                    // 8 - stream released up to this time (v16)
+    pub execution_nonce: u64,                   // 8 - completed distributions, for keeper retries (v18)
+    pub cliff_redistribute: bool,               // 1 - pre-cliff shares go to vested recipients instead of held (v19)
+    pub claim_delegates: Vec<ClaimDelegate>,    // 4 + (64 * 20) - recipient-registered claim triggers (v20)
+    pub reserve: Pubkey,                        // 32 - treasury wallet taking reserve_bps of each split (v21)
+    pub reserve_bps: u16,                       // 2 - fixed at creation; recipients total 9900 - reserve_bps (v21)
+    pub paused: bool,                           // 1 - authority stop for distribution; gates emergency_drain (v22)
+    pub authorities: Vec<Pubkey>,               // 4 + (32 * 7) - M-of-N approvers for sensitive ops (v23)
+    pub threshold: u8,                          // 1 - approvals required, 0 = single authority (v23)
+    pub last_seen_balance: u64,                 // 8 - undistributed vault balance after the last split (v24)
+    pub pending_protocol_fee: u64,              // 8 - fee deferred while the protocol ATA was missing (v25)
+    pub claim_delay_seconds: u32,               // 4 - held funds claimable this long after being held, 0 = instant (v26)
+    pub remainder_recipient_index: Option<u8>,  // 2 - recipient absorbing all rounding dust, None = protocol (v27)
+    pub require_acceptance: bool,               // 1 - shares held until the recipient calls accept_recipient (v28)
+    pub last_authority_activity_ts: i64,        // 8 - last authority instruction, starts the takeover window (v29)
+    pub min_execute_interval_seconds: u32,      // 4 - cooldown between distributions, 0 = none (v31)
+    pub last_execution_ts: i64,                 // 8 - when the last distribution completed (v31)
+    pub referrer: Option<Pubkey>,               // 1 + 32 - partner that referred the config, attribution only (v32)
+    pub audit_log_enabled: bool,                // 1 - every distribution is appended to the AuditLog PDA (v33)
+    pub claim_receipts: bool,                   // 1 - claims must write a ClaimReceipt PDA (v34)
+    pub claim_receipt_nonce: u64,               // 8 - receipts written, seeds the next ClaimReceipt (v34)
+    pub ops_authority: Pubkey,                  // 32 - pauses, drains and rescues; defaults to authority (v35)
+    pub executor_tip_lamports: u64,             // 8 - SOL paid to the executor per distribution, 0 = none (v36)
+    pub executor_reserve_lamports: u64,         // 8 - prefunded SOL on this PDA backing the tip (v36)
+    pub pending_recipients: Option<PendingRecipients>, // 1 + 4 + 94*20 + 4 + 4*20 + 8 - scheduled recipient set (v37)
+    pub round_fee_floor: u64,                   // 8 - fee floor top-up taken off a chunked round (v38)
+}
+
+/// A validated recipient set queued by queue_recipients, promoted by the first
+/// execution at or after `effective_ts`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PendingRecipients {
+    pub recipients: Vec<Recipient>,
+    pub share_ppm: Vec<u32>,                    // Ppm precision only
+    pub effective_ts: i64,
+}
+
+impl SplitConfig {
+    /// Whether `address` is a frozen recipient (claims are refused)
+    pub fn is_frozen(&self, address: &Pubkey) -> bool {
+        self.recipients.iter().any(|r| r.address == *address && r.frozen)
+    }
+
+    /// Whether `address` is a recipient whose cliff hasn't passed at `now` (claims are refused)
+    pub fn before_cliff(&self, address: &Pubkey, now: i64) -> bool {
+        self.recipients.iter().any(|r| r.address == *address && r.cliff_ts > now)
+    }
+
+    /// Whether `address` is a recipient still owing accept_recipient (claims are refused)
+    pub fn awaiting_acceptance(&self, address: &Pubkey) -> bool {
+        self.require_acceptance
+            && self.recipients.iter().any(|r| r.address == *address && !r.accepted)
+    }
+
+    /// Delegate `recipient` registered to trigger their claims, if any
+    pub fn claim_delegate(&self, recipient: &Pubkey) -> Option<Pubkey> {
+        self.claim_delegates.iter()
+            .find(|d| d.recipient == *recipient)
+            .map(|d| d.delegate)
+    }
+
+    /// A chunked split has started but not been finalized
+    pub fn split_in_progress(&self) -> bool {
+        self.split_cursor > 0
+    }
+}
+
+#[account]
+pub struct FeeExemptMints {
+    pub mints: Vec<Pubkey>,                     // 4 + (32 * 16) - mints that pay no protocol fee
+    pub bump: u8,                               // 1
+}
+
+impl FeeExemptMints {
+    pub fn is_exempt(&self, mint: &Pubkey) -> bool {
+        self.mints.contains(mint)
+    }
+}
+
+#[account]
+pub struct ProtocolFeeWallets {
+    pub wallets: Vec<ProtocolFeeWallet>,        // 4 + (64 * 16) - per-mint protocol fee wallets
+    pub bump: u8,                               // 1
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProtocolFeeWallet {
+    pub mint: Pubkey,                           // 32
+    pub wallet: Pubkey,                         // 32 - owner of the ATA execution fees go to
+}
+
+impl ProtocolFeeWallets {
+    /// Wallet whose ATA receives `mint`'s protocol fees (PROTOCOL_WALLET by default)
+    pub fn wallet_for(&self, mint: &Pubkey) -> Pubkey {
+        self.wallets.iter()
+            .find(|w| w.mint == *mint)
+            .map_or(PROTOCOL_WALLET, |w| w.wallet)
+    }
+}
+
+#[account]
+pub struct AuthorityIndex {
+    pub authority: Pubkey,                      // 32
+    pub configs: Vec<Pubkey>,                   // 4 + (32 * 64) - configs created or backfilled while indexed
+    pub bump: u8,                               // 1
+}
+
+#[account]
+pub struct AuditLog {
+    pub config: Pubkey,                         // 32
+    pub head: u8,                               // 1 - oldest entry (next overwritten) once full
+    pub entries: Vec<AuditEntry>,               // 4 + (24 * 32) - ring buffer of recent distributions
+    pub bump: u8,                               // 1
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuditEntry {
+    pub timestamp: i64,                         // 8
+    pub total_amount: u64,                      // 8 - balance split this round
+    pub protocol_fee: u64,                      // 8
+}
+
+impl AuditLog {
+    /// Appends a distribution, overwriting the oldest once AUDIT_LOG_CAPACITY is reached
+    pub fn record(&mut self, entry: AuditEntry) {
+        if self.entries.len() < AUDIT_LOG_CAPACITY {
+            self.entries.push(entry);
+        } else {
+            self.entries[self.head as usize] = entry;
+            self.head = ((self.head as usize + 1) % AUDIT_LOG_CAPACITY) as u8;
+        }
+    }
+
+    /// Entries oldest first
+    pub fn chronological(&self) -> Vec<AuditEntry> {
+        let mut entries = self.entries.clone();
+        entries.rotate_left(self.head as usize);
+        entries
+    }
+}
+
+#[account]
+pub struct ClaimReceipt {
+    pub recipient: Pubkey,                      // 32 - first, for per-recipient memcmp queries
+    pub config: Pubkey,                         // 32
+    pub mint: Pubkey,                           // 32
+    pub amount: u64,                            // 8 - base units claimed
+    pub timestamp: i64,                         // 8
+    pub nonce: u64,                             // 8 - config's claim_receipt_nonce at the claim
+    pub bump: u8,                               // 1
+}
+
+#[account]
+pub struct FeeTiers {
+    pub mint: Pubkey,                           // 32
+    pub tiers: Vec<FeeTier>,                    // 4 + (10 * 8) - ascending min_volume
+    pub bump: u8,                               // 1
+    pub min_fee: u64,                           // 8 - protocol fee floor in base units, 0 = none
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FeeTier {
+    pub min_volume: u64,                        // 8 - lifetime total_distributed (base units) to qualify
+    pub fee_bps: u16,                           // 2 - protocol fee at this tier, at most PROTOCOL_FEE_BPS
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Recipient {
+    pub address: Pubkey,                     // 32
+    pub percentage_bps: u16,                 // 2
+    pub destination: RecipientDestination,   // 1 + 32 (v5)
+    pub label: [u8; 16],                     // 16 - off-chain reconciliation id, informational (v8)
+    pub frozen: bool,                        // 1 - share is held and claims refused (v15)
+    pub cliff_ts: i64,                       // 8 - unix time the share starts paying, 0 = none (v19)
+    pub accepted: bool,                      // 1 - recipient signed for their destination (v28)
+    pub push_paused: bool,                   // 1 - share is held but claims still work (v30)
+}
+
+/// Recipient layout before v5 - only read by migrate_split_config
+#[derive(AnchorDeserialize)]
+struct RecipientV1 {
+    address: Pubkey,
+    percentage_bps: u16,
+}
+
+/// Recipient layout v5-v7 - only read by migrate_split_config
+#[derive(AnchorDeserialize)]
+struct RecipientV5 {
+    address: Pubkey,
+    percentage_bps: u16,
+    destination: RecipientDestination,
+}
+
+/// Recipient layout v8-v14 - only read by migrate_split_config
+#[derive(AnchorDeserialize)]
+struct RecipientV8 {
+    address: Pubkey,
+    percentage_bps: u16,
+    destination: RecipientDestination,
+    label: [u8; 16],
+}
+
+/// Recipient layout v15-v18 - only read by migrate_split_config
+#[derive(AnchorDeserialize)]
+struct RecipientV15 {
+    address: Pubkey,
+    percentage_bps: u16,
+    destination: RecipientDestination,
+    label: [u8; 16],
+    frozen: bool,
+}
+
+/// Recipient layout v19-v27 - only read by migrate_split_config
+#[derive(AnchorDeserialize)]
+struct RecipientV19 {
+    address: Pubkey,
+    percentage_bps: u16,
+    destination: RecipientDestination,
+    label: [u8; 16],
+    frozen: bool,
+    cliff_ts: i64,
+}
+
+/// Recipient layout v28-v29 - only read by migrate_split_config
+#[derive(AnchorDeserialize)]
+struct RecipientV28 {
+    address: Pubkey,
+    percentage_bps: u16,
+    destination: RecipientDestination,
+    label: [u8; 16],
+    frozen: bool,
+    cliff_ts: i64,
+    accepted: bool,
+}
+
+/// Unit recipient shares are expressed in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Precision {
+    /// percentage_bps, recipients total 9900
+    #[default]
+    Bps,
+    /// share_ppm, recipients total 990_000
+    Ppm,
+}
+
+/// Why a recipient payment was held as unclaimed instead of transferred
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HeldReason {
+    AtaMissing,
+    InvalidOwnerProgram,
+    InvalidAccount,
+    WrongOwner,
+    WrongMint,
+    TransferFailed,
+    /// Config is pull-only; recipient claims via claim_unclaimed
+    PullOnly,
+    /// Not the recipient's destination account (validate_recipients only -
+    /// execute_split rejects these outright)
+    NotDestination,
+    /// Share below the config's min_payout; accrues until claimed
+    BelowMinPayout,
+    /// Recipient frozen by the authority; claimable once unfrozen
+    Frozen,
+    /// Recipient's vesting cliff hasn't passed; claimable after cliff_ts
+    BeforeCliff,
+    /// Config requires acceptance and the recipient hasn't called accept_recipient
+    NotAccepted,
+    /// Token account under the other token program (validate_recipients only -
+    /// execute_split rejects these outright)
+    WrongProgram,
+    /// Authority paused pushes to this recipient; claims still work
+    PushPaused,
+}
+
+impl HeldReason {
+    /// Matching error for paths that fail instead of holding (e.g. tips)
+    pub fn error(self) -> ErrorCode {
+        match self {
+            HeldReason::AtaMissing => ErrorCode::RecipientATADoesNotExist,
+            HeldReason::InvalidOwnerProgram => ErrorCode::RecipientATAInvalidOwner,
+            HeldReason::InvalidAccount => ErrorCode::RecipientATAInvalid,
+            HeldReason::WrongOwner => ErrorCode::RecipientATAWrongOwner,
+            HeldReason::WrongMint => ErrorCode::RecipientATAWrongMint,
+            HeldReason::TransferFailed => ErrorCode::TransferFailed,
+            HeldReason::NotDestination => ErrorCode::RecipientATAMismatch,
+            HeldReason::PullOnly => ErrorCode::PullOnlyConfig,
+            HeldReason::BelowMinPayout => ErrorCode::BelowMinPayout,
+            HeldReason::Frozen => ErrorCode::RecipientIsFrozen,
+            HeldReason::BeforeCliff => ErrorCode::RecipientBeforeCliff,
+            HeldReason::NotAccepted => ErrorCode::RecipientNotAccepted,
+            HeldReason::WrongProgram => ErrorCode::RecipientATAWrongProgram,
+            HeldReason::PushPaused => ErrorCode::RecipientPushPaused,
+        }
+    }
+}
+
+/// Token account a recipient is paid into
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum RecipientDestination {
+    /// Canonical ATA for (address, mint, token_program)
+    Ata,
+    /// Specific token account (exchanges, multisig treasuries). Mint and owner are
+    /// validated but the account isn't derivable, so clients can't verify it from
+    /// the recipient address alone
+    ExplicitAccount(Pubkey),
+    /// Vault of another SplitConfig (same mint), which splits the share further on its
+    /// own execute_split - no CPI recursion, so compute per level stays flat and each
+    /// level is executed (and pays the protocol fee) separately. Held shares are claimable
+    /// by the recipient address
+    SubSplit(Pubkey),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimDelegate {
+    pub recipient: Pubkey,         // 32
+    pub delegate: Pubkey,          // 32 - may trigger claims, never receives funds
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UnclaimedAmount {
+    pub recipient: Pubkey,         // 32
+    pub amount: u64,               // 8
+    pub timestamp: i64,            // 8
+}
+
+/// Owed balance carried into a fresh config by seed_unclaimed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UnclaimedSeed {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[account]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,                          // 32
+    pub creation_fee_lamports: u64,             // 8
+    pub creation_fee_tokens: u64,               // 8 - in config mint base units
+    pub bump: u8,                               // 1
+}
+
+#[account]
+pub struct AdminConfig {
+    pub admin: Pubkey,                          // 32 - can pause and transfer admin
+    pub global_paused: bool,                    // 1 - circuit breaker for all configs
+    pub bump: u8,                               // 1
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EffectiveShare {
+    pub recipient: Pubkey,
+    pub stored_bps: u16,
+    pub effective_bps: u16,
+    pub estimated_amount: u64,           // at current vault balance
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Claimable {
+    pub recipient: Pubkey,
+    pub total: u64,
+    pub entries: Vec<ClaimableEntry>,   // storage order, at most MAX_UNCLAIMED
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimableEntry {
+    pub amount: u64,
+    pub timestamp: i64,                  // when the entry was held
+    pub expires_at: i64,                 // sweepable by force_close_split_config from here
+}
+
+/// Treasury cut requested at creation, paid alongside recipients and the protocol fee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct Reserve {
+    pub destination: Pubkey,             // wallet whose ATA receives the cut
+    pub bps: u16,                        // 1..=REQUIRED_SPLIT_TOTAL - MIN_RECIPIENT_TOTAL_BPS
+}
+
+/// Optional create_split_config settings
+/// Token-2022 mints with a permanent delegate (who could claw back the vault) are
+/// rejected unless `allow_permanent_delegate` is set; transfer-hook mints always are
+/// A `reserve` takes a fixed cut of every split; recipients then total
+/// 9900 - reserve bps (at least MIN_RECIPIENT_TOTAL_BPS)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CreateOptions {
+    pub allow_permanent_delegate: bool,
+    pub reserve: Option<Reserve>,
+    pub claim_delay_seconds: u32,        // delays claims of held funds, 0 = claim instantly
+    pub referrer: Option<Pubkey>,        // referring partner, attribution only (no fee share)
+}
+
+impl CreateOptions {
+    /// No reserve, claim delay or referrer
+    fn with_permanent_delegate(allow_permanent_delegate: bool) -> Self {
+        Self {
+            allow_permanent_delegate,
+            reserve: None,
+            claim_delay_seconds: 0,
+            referrer: None,
+        }
+    }
+}
+
+/// Authority operations that need M-of-N approval on multisig configs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SensitiveOp {
+    UpdateRecipients,
+    SetPaused,
+    EmergencyDrain,
+    Close,
+    SetMultisig,
+}
+
+/// First invariant verify_config found broken, in check order
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConfigViolation {
+    /// Layout predates CURRENT_VERSION; run migrate_split_config
+    StaleVersion,
+    /// Account is not the PDA for its stored authority and mint
+    NonCanonicalAddress,
+    /// Stored bump differs from the canonical bump
+    BumpMismatch,
+    /// Vault is not the config's vault, or not owned by the config
+    VaultMismatch,
+    /// Vault holds a different mint than the config
+    VaultMintMismatch,
+    /// The same address appears twice in recipients
+    DuplicateRecipient,
+    /// Shares don't total 99% (100% on a fee-exempt mint) less the reserve in the config's precision
+    InvalidShareTotal,
+    /// Unclaimed entries sum to more than the vault holds
+    UnclaimedExceedsVault,
+}
+
+/// Outcome of execute_split/finalize_split, readable by CPI callers via get_return_data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ExecuteResult {
+    pub distributed: u64,
+    pub protocol_fee: u64,
+    pub held: u64,                       // held as unclaimed this round (excludes strict_fee dust)
+}
+
+/// Per-config outcome in MultiSplitExecuted; error_code is set when the config was skipped
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MultiSplitResult {
+    pub config: Pubkey,
+    pub distributed: u64,
+    pub protocol_fee: u64,
+    pub held: u64,
+    pub error_code: Option<u32>,
+}
+
+// Events
+
+#[event]
+pub struct SplitConfigCreated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub recipients_count: u8,
+    pub labels: Vec<[u8; 16]>,
+    pub metadata_uri: String,
+    pub claim_delay_seconds: u32,
+    pub timestamp: i64,
+    pub referrer: Option<Pubkey>,  // referring partner (schema 3), None = direct
+    pub mint_freeze_authority: Option<Pubkey>, // can freeze the vault, halting execution (schema 5)
+}
+
+#[event]
+pub struct SplitExecuted {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub vault: Pubkey,
+    pub total_amount: u64,
+    pub recipients_distributed: u64,
+    pub protocol_fee: u64,
+    pub held_count: u64,
+    pub dust: u64,               // strict_fee only: rounding dust held for the first active recipient
+    pub executor: Pubkey,
+    pub timestamp: i64,
+    pub fee_tier: Option<u8>,    // volume tier applied (schema 2), None = full fee
+    pub fee_bps: u16,            // fee rate charged this round (schema 2)
+}
+
+#[event]
+pub struct RecipientPaymentHeld {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reason: HeldReason,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnclaimedFundsClaimed {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SplitConfigUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub old_recipients_count: u8,
+    pub new_recipients_count: u8,
+    pub labels: Vec<[u8; 16]>,
+    pub recipients_epoch: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SplitConfigMigrated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ShareRenounced {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub freed_bps: u16,
+    pub remaining_recipients: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigCreationFeePaid {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub lamports: u64,
+    pub tokens: u64,
+    pub timestamp: i64,
+}
 
-    #[account(
-        init,
-        payer = authority,
-        associated_token::mint = mint,
-        associated_token::authority = split_config,
-        associated_token::token_program = token_program,
-    )]
-    pub vault: InterfaceAccount<'info, TokenAccount>,
+#[event]
+pub struct CreationFeeUpdated {
+    pub schema_version: u8,
+    pub admin: Pubkey,
+    pub creation_fee_lamports: u64,
+    pub creation_fee_tokens: u64,
+    pub timestamp: i64,
+}
 
-    pub mint: InterfaceAccount<'info, Mint>,
+#[event]
+pub struct ExecuteBoundsUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub min_execute_amount: u64,
+    pub max_execute_amount: u64,
+    pub timestamp: i64,
+}
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+#[event]
+pub struct ExecuteIntervalUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub min_execute_interval_seconds: u32,
+    pub timestamp: i64,
+}
 
-    pub token_program: Interface<'info, TokenInterface>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct SplitRangeExecuted {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub start: u8,
+    pub end: u8,
+    pub round_balance: u64,
+    pub distributed: u64,
+    pub held_amount: u64,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct ExecuteSplit<'info> {
-    #[account(
-        mut,
-        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
-        bump = split_config.bump
-    )]
-    pub split_config: Box<Account<'info, SplitConfig>>,
+#[event]
+pub struct RecipientValidation {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub ok: bool,
+    pub reason: Option<HeldReason>,
+    pub timestamp: i64,
+}
 
-    #[account(mut)]
-    pub vault: InterfaceAccount<'info, TokenAccount>,
+#[event]
+pub struct TipPaid {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub tip_recipient: Pubkey,
+    pub tip_bps: u16,
+    pub amount: u64,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
 
-    #[account(
-        constraint = mint.key() == split_config.mint
-    )]
-    pub mint: InterfaceAccount<'info, Mint>,
+/// A recipient is paid into an explicit token account instead of their ATA.
+/// Only mint and owner are checked on-chain - the account is not derivable from
+/// the recipient address, so indexers should surface it for review
+#[event]
+pub struct ExplicitDestinationSet {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub token_account: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PullOnlyUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub pull_only: bool,
+    pub hold_protocol_fee: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataUriUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub metadata_uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExecutorAllowlistUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub executors: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinPayoutUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub min_payout: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ForceClosed {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub recipients: Vec<Pubkey>,
+    pub amounts: Vec<u64>,
+    pub swept: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Deposited {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub recipients_epoch: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientRegistered {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub address: Pubkey,
+    pub percentage_bps: u16,
+    pub share_ppm: u32,          // 0 for Bps precision
+    pub index: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnclaimedCompacted {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub removed: u8,
+    pub remaining: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StrictFeeUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub strict_fee: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientShareUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub old_bps: u16,
+    pub new_bps: u16,
+    pub rebalance_target: Pubkey,
+    pub target_bps: u16,
+    pub recipients_epoch: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GlobalPauseUpdated {
+    pub schema_version: u8,
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub schema_version: u8,
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReleaseAuthorityUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub release_authority: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReleaseSignaled {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub release_authority: Pubkey,
+    pub released: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientFrozen {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub recipient: Pubkey,
+    pub frozen: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted by execute_adhoc_split next to the round's SplitExecuted
+#[event]
+pub struct AdhocSplitExecuted {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub recipients: Vec<Pubkey>,    // ad-hoc set, stored recipients are untouched
+    pub total_amount: u64,
+    pub recipients_distributed: u64,
+    pub protocol_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientPushPaused {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub recipient: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AssetsRescued {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub mint: Option<Pubkey>, // None for lamports
+    pub amount: u64,
+    pub timestamp: i64,
+    pub ops_authority: Pubkey,    // signer, the rescued funds' recipient (schema 4)
+}
+
+#[event]
+pub struct StreamRateUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub stream_rate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolFeeWalletUpdated {
+    pub schema_version: u8,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub wallet: Pubkey,             // PROTOCOL_WALLET once the entry is removed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeExemptMintUpdated {
+    pub schema_version: u8,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub exempt: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CliffModeUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub redistribute: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimDelegateUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub delegate: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegatedClaimExecuted {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub delegate: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReservePaid {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub reserve: Pubkey,
+    pub reserve_bps: u16,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigPauseUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+    pub ops_authority: Pubkey,    // signer (schema 4)
+}
+
+#[event]
+pub struct EmergencyDrained {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub cleared_unclaimed: u8,    // unclaimed entries dropped with the drain
+    pub timestamp: i64,
+    pub ops_authority: Pubkey,    // signer (schema 4)
+}
+
+#[event]
+pub struct OpsAuthorityUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub previous: Pubkey,
+    pub ops_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub authorities: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigApproved {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub operation: SensitiveOp,
+    pub approvers: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultFunded {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub amount: u64,
+    pub funder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BalanceIncrease {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub delta: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientExited {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub freed_bps: u16,
+    pub forfeited: u64,             // held dust dropped back into the pool
+    pub remaining_recipients: u8,
+    pub timestamp: i64,
+}
 
-    /// CHECK: Can be anyone (permissionless execution)
-    pub executor: AccountInfo<'info>,
+#[event]
+pub struct ProtocolFeeDeferred {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub amount: u64,                // this round's fee
+    pub total_pending: u64,         // pending_protocol_fee after deferral
+    pub timestamp: i64,
+}
 
-    pub token_program: Interface<'info, TokenInterface>,
+#[event]
+pub struct RecipientsQueued {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub recipients_count: u8,
+    pub labels: Vec<[u8; 16]>,
+    pub effective_ts: i64,          // first execution at or after this promotes the set
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct ClaimUnclaimed<'info> {
-    pub recipient: Signer<'info>,
+#[event]
+pub struct RecipientsPromoted {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub old_recipients_count: u8,
+    pub new_recipients_count: u8,
+    pub recipients_epoch: u32,
+    pub effective_ts: i64,
+    pub timestamp: i64,
+}
 
-    #[account(
-        mut,
-        seeds = [b"split_config", split_config.authority.as_ref(), split_config.mint.as_ref()],
-        bump = split_config.bump
-    )]
-    pub split_config: Box<Account<'info, SplitConfig>>,
+#[event]
+pub struct PendingRecipientsCancelled {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub effective_ts: i64,
+    pub timestamp: i64,
+}
 
-    #[account(mut)]
-    pub vault: InterfaceAccount<'info, TokenAccount>,
+#[event]
+pub struct ExecutorReserveFunded {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub lamports: u64,
+    pub reserve_lamports: u64,      // reserve after the top-up
+    pub timestamp: i64,
+}
 
-    #[account(
-        constraint = mint.key() == split_config.mint
-    )]
-    pub mint: InterfaceAccount<'info, Mint>,
+#[event]
+pub struct ExecutorTipUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub lamports: u64,              // per-distribution tip, 0 = disabled
+    pub timestamp: i64,
+}
 
-    #[account(
-        mut,
-        associated_token::mint = split_config.mint,
-        associated_token::authority = recipient,
-        associated_token::token_program = token_program,
-    )]
-    pub recipient_ata: InterfaceAccount<'info, TokenAccount>,
+#[event]
+pub struct ExecutorTipPaid {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub executor: Pubkey,
+    pub lamports: u64,
+    pub reserve_lamports: u64,      // reserve left after the tip
+    pub timestamp: i64,
+}
 
-    pub token_program: Interface<'info, TokenInterface>,
+#[event]
+pub struct ClaimReceiptsUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-#[instruction(new_recipients: Vec<Recipient>)]
-pub struct UpdateSplitConfig<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+#[event]
+pub struct AuditLogEnabled {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub audit_log: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
 
-    #[account(
-        mut,
-        has_one = authority,
-        seeds = [b"split_config", authority.key().as_ref(), split_config.mint.as_ref()],
-        bump = split_config.bump
-    )]
-    pub split_config: Box<Account<'info, SplitConfig>>,
+#[event]
+pub struct ProtocolFeeSwept {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub amount: u64,                // pending_protocol_fee sent to the protocol ATA
+    pub timestamp: i64,
+}
 
-    #[account(
-        mut,
-        constraint = vault.key() == split_config.vault @ ErrorCode::InvalidVault
-    )]
-    pub vault: InterfaceAccount<'info, TokenAccount>,
+#[event]
+pub struct RecipientAtasEnsured {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub payer: Pubkey,
+    pub created: Vec<Pubkey>,       // ATAs created by this call
+    pub existing: Vec<Pubkey>,      // ATAs that were already there
+    pub timestamp: i64,
 }
 
-// Note: CloseSplitConfig temporarily removed
-// #[derive(Accounts)]
-// pub struct CloseSplitConfig<'info> {
-//     ...
-// }
+#[event]
+pub struct InterestBearingSplit {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub total_amount: u64,          // raw units split this round
+    pub ui_amount: String,          // same amount with interest accrued to now
+    pub rate_bps: i16,              // mint's current annual rate
+    pub timestamp: i64,
+}
 
-// Data Structures
+#[event]
+pub struct ClaimDelayUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub claim_delay_seconds: u32,
+    pub timestamp: i64,
+}
 
-#[account]
-pub struct SplitConfig {
-    pub version: u8,                            // 1 (for future migrations)
-    pub authority: Pubkey,                      // 32
-    pub mint: Pubkey,                           // 32
-    pub vault: Pubkey,                          // 32
-    pub recipients: Vec<Recipient>,             // 4 + (34 * n)
-    pub unclaimed_amounts: Vec<UnclaimedAmount>,// 4 + (48 * n)
-    pub bump: u8,                               // 1
+#[event]
+pub struct MultiSplitExecuted {
+    pub schema_version: u8,
+    pub executor: Pubkey,
+    pub results: Vec<MultiSplitResult>,
+    pub timestamp: i64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct Recipient {
-    pub address: Pubkey,           // 32
-    pub percentage_bps: u16,       // 2
+#[event]
+pub struct RemainderRecipientUpdated {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub recipient: Option<Pubkey>,
+    pub timestamp: i64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub struct UnclaimedAmount {
-    pub recipient: Pubkey,         // 32
-    pub amount: u64,               // 8
-    pub timestamp: i64,            // 8
+#[event]
+pub struct UnclaimedFundsSwapped {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub swap_program: Pubkey,
+    pub timestamp: i64,
 }
 
-// Events
+#[event]
+pub struct OrphanedUnclaimed {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
 
 #[event]
-pub struct SplitConfigCreated {
+pub struct RequireAcceptanceUpdated {
+    pub schema_version: u8,
     pub config: Pubkey,
     pub authority: Pubkey,
+    pub require_acceptance: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientAccepted {
+    pub schema_version: u8,
+    pub config: Pubkey,
+    pub recipient: Pubkey,
+    pub destination: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeTiersUpdated {
+    pub schema_version: u8,
+    pub admin: Pubkey,
     pub mint: Pubkey,
-    pub vault: Pubkey,
-    pub recipients_count: u8,
+    pub tiers: Vec<FeeTier>,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct SplitExecuted {
+pub struct MinProtocolFeeUpdated {
+    pub schema_version: u8,
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub min_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientTakeoverExecuted {
+    pub schema_version: u8,
     pub config: Pubkey,
-    pub vault: Pubkey,
-    pub total_amount: u64,
-    pub recipients_distributed: u64,
-    pub protocol_fee: u64,
-    pub held_count: u64,
-    pub executor: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub signed_units: u64,
+    pub total_units: u64,
+    pub inactive_seconds: i64,
     pub timestamp: i64,
 }
 
+/// Emitted by execute_split in `debug` feature builds only
 #[event]
-pub struct RecipientPaymentHeld {
+pub struct ExecuteComputeUnits {
+    pub schema_version: u8,
     pub config: Pubkey,
-    pub recipient: Pubkey,
-    pub amount: u64,
-    pub reason: String,
+    pub recipient_count: u8,
+    pub units_at_start: u64,   // sol_remaining_compute_units on entry
+    pub units_at_end: u64,     // after the split, before returning
     pub timestamp: i64,
 }
 
 #[event]
-pub struct UnclaimedFundsClaimed {
+pub struct UnclaimedSeeded {
+    pub schema_version: u8,
     pub config: Pubkey,
     pub recipient: Pubkey,
     pub amount: u64,
@@ -635,21 +7576,21 @@ pub struct UnclaimedFundsClaimed {
 }
 
 #[event]
-pub struct SplitConfigUpdated {
+pub struct SplitConfigClosed {
+    pub schema_version: u8,
     pub config: Pubkey,
     pub authority: Pubkey,
-    pub old_recipients_count: u8,
-    pub new_recipients_count: u8,
     pub timestamp: i64,
 }
 
-// Note: SplitConfigClosed event temporarily removed
-// #[event]
-// pub struct SplitConfigClosed {
-//     pub config: Pubkey,
-//     pub authority: Pubkey,
-//     pub timestamp: i64,
-// }
+#[event]
+pub struct AuthorityIndexInitialized {
+    pub schema_version: u8,
+    pub authority: Pubkey,
+    pub authority_index: Pubkey,
+    pub configs_count: u8,          // configs backfilled at creation
+    pub timestamp: i64,
+}
 
 // Error Codes
 
@@ -658,7 +7599,7 @@ pub enum ErrorCode {
     #[msg("Recipients must total exactly 9900 basis points (99%)")]
     InvalidSplitTotal,
 
-    #[msg("Must have between 2 and 20 recipients")]
+    #[msg("Must have between 1 and 20 recipients")]
     InvalidRecipientCount,
 
     #[msg("Duplicate recipient address detected")]
@@ -670,7 +7611,7 @@ pub enum ErrorCode {
     #[msg("Recipient percentage cannot be zero")]
     ZeroPercentage,
 
-    #[msg("Vault balance must be 0 to update or close config")]
+    #[msg("Vault must hold nothing to split (update) or be empty (close)")]
     VaultNotEmpty,
 
     #[msg("Provided vault account does not match config vault")]
@@ -703,7 +7644,7 @@ pub enum ErrorCode {
     #[msg("Recipient ATA should be read-only during config creation")]
     RecipientATAShouldBeReadOnly,
 
-    #[msg("Too many unclaimed entries (max 20)")]
+    #[msg("Too many unclaimed entries (MAX_UNCLAIMED = 32) - claim or compact first")]
     TooManyUnclaimedEntries,
 
     #[msg("Protocol fee account was not provided in remaining_accounts")]
@@ -717,4 +7658,261 @@ pub enum ErrorCode {
 
     #[msg("Config still has unclaimed funds - cannot close")]
     UnclaimedFundsExist,
+
+    #[msg("Config uses an older layout version - call migrate_split_config first")]
+    ConfigNeedsMigration,
+
+    #[msg("Signer is not an active recipient of this config")]
+    NotARecipient,
+
+    #[msg("Token creation fee requires authority token account and protocol ATA")]
+    MissingCreationFeeAccount,
+
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+
+    #[msg("Native claim requires the wrapped SOL mint")]
+    NotNativeMint,
+
+    #[msg("Vault balance exceeds the configured max_execute_amount")]
+    AboveMaxExecuteAmount,
+
+    #[msg("min_execute_amount must not exceed max_execute_amount")]
+    InvalidExecuteBounds,
+
+    #[msg("A chunked split is in progress - finish ranges and call finalize_split first")]
+    ChunkedSplitInProgress,
+
+    #[msg("Split range must start at the current cursor and stay within recipients")]
+    InvalidSplitRange,
+
+    #[msg("All recipient ranges must execute before finalize_split")]
+    SplitRangeIncomplete,
+
+    #[msg("Executor must sign to pay rent for created recipient ATAs")]
+    ExecutorMustSign,
+
+    #[msg("Tip must be between 1 and MAX_TIP_BPS bps and requires a tip recipient")]
+    InvalidTip,
+
+    #[msg("Tip ATA was not provided in remaining_accounts")]
+    MissingTipAccount,
+
+    #[msg("Recipient account does not match the ATA derived for the recipient")]
+    RecipientATAMismatch,
+
+    #[msg("Same account passed more than once in remaining_accounts")]
+    DuplicateAccount,
+
+    #[msg("Token transfer to recipient failed")]
+    TransferFailed,
+
+    #[msg("Not supported for this config's share precision")]
+    UnsupportedPrecision,
+
+    #[msg("Pull-only config: funds are claimed, not pushed (hold_protocol_fee requires pull_only)")]
+    PullOnlyConfig,
+
+    #[msg("Metadata URI exceeds MAX_METADATA_URI_LEN bytes")]
+    MetadataUriTooLong,
+
+    #[msg("Executor allowlist exceeds MAX_EXECUTORS")]
+    TooManyExecutors,
+
+    #[msg("Duplicate executor in allowlist")]
+    DuplicateExecutor,
+
+    #[msg("Executor is not on this config's allowlist")]
+    ExecutorNotAllowed,
+
+    #[msg("Share is below the config's min_payout")]
+    BelowMinPayout,
+
+    #[msg("Unclaimed funds are not old enough to sweep (UNCLAIMED_EXPIRY_SECONDS)")]
+    UnclaimedNotExpired,
+
+    #[msg("Vault holds undistributed funds - execute_split before force close")]
+    UndistributedVaultFunds,
+
+    #[msg("Funds belong to a different recipient set - execute_split before changing recipients")]
+    StaleRecipientsForFunds,
+
+    #[msg("Sub-split must reference another SplitConfig account")]
+    InvalidSubSplit,
+
+    #[msg("Protocol is globally paused by the admin")]
+    GloballyPaused,
+
+    #[msg("Distribution is awaiting the release authority's signal")]
+    NotReleased,
+
+    #[msg("Claim amount must be non-zero and at most the held entry")]
+    InvalidClaimAmount,
+
+    #[msg("Recipient is frozen by the config authority")]
+    RecipientIsFrozen,
+
+    #[msg("Nothing to rescue")]
+    NothingToRescue,
+
+    #[msg("The config mint is distributed, not rescued")]
+    CannotRescueConfigMint,
+
+    #[msg("Fee-exempt mint list exceeds MAX_FEE_EXEMPT_MINTS")]
+    TooManyFeeExemptMints,
+
+    #[msg("Mint is already fee-exempt")]
+    DuplicateFeeExemptMint,
+
+    #[msg("Mint is not fee-exempt")]
+    MintNotFeeExempt,
+
+    #[msg("Mint lost its fee exemption - update recipients to total 99%")]
+    FeeExemptionRevoked,
+
+    #[msg("split_config is not the canonical PDA for its authority and mint")]
+    NonCanonicalSplitConfig,
+
+    #[msg("Mint has a permanent delegate that could claw back vault funds")]
+    UnsafeMintExtension,
+
+    #[msg("Mint has a transfer hook - hook accounts are not supported in execute_split")]
+    TransferHookUnsupported,
+
+    #[msg("expected_nonce does not match execution_nonce - already executed")]
+    StaleNonce,
+
+    #[msg("Recipient vesting cliff has not passed")]
+    RecipientBeforeCliff,
+
+    #[msg("Too many claim delegates registered (MAX_RECIPIENTS)")]
+    TooManyClaimDelegates,
+
+    #[msg("Signer is not the recipient's registered claim delegate")]
+    NotClaimDelegate,
+
+    #[msg("Reserve bps must be non-zero and leave recipients at least MIN_RECIPIENT_TOTAL_BPS")]
+    InvalidReserve,
+
+    #[msg("Reserve ATA missing from remaining accounts (second to last)")]
+    MissingReserveAccount,
+
+    #[msg("Config is paused by its authority")]
+    ConfigPaused,
+
+    #[msg("Config must be paused before an emergency drain")]
+    ConfigNotPaused,
+
+    #[msg("Vault is empty - nothing to drain")]
+    NothingToDrain,
+
+    #[msg("Authority set must be unique, at most MAX_AUTHORITIES, with 0 < threshold <= len (or empty with 0)")]
+    InvalidMultisig,
+
+    #[msg("Co-signer is not a signing member of the authority set")]
+    InvalidCosigner,
+
+    #[msg("Not enough authority approvals for this operation")]
+    InsufficientApprovals,
+
+    #[msg("Fund amount must be greater than zero")]
+    InvalidFundAmount,
+
+    #[msg("Percentages must sum to exactly 10000 (100.00%)")]
+    InvalidPercentTotal,
+
+    #[msg("A percentage scales to zero bps of the recipient budget")]
+    ZeroScaledShare,
+
+    #[msg("Recipients must be sorted by address in ascending order")]
+    RecipientsNotSorted,
+
+    #[msg("Held funds are still inside the claim delay window")]
+    ClaimTooEarly,
+
+    #[msg("execute_split_multi takes 1 to MAX_MULTI_CONFIGS configs")]
+    TooManyConfigs,
+
+    #[msg("Remainder recipient must be an in-range, unfrozen recipient with a non-zero share")]
+    InvalidRemainderRecipient,
+
+    #[msg("Swap program must be an executable other than cascadepay, into a different mint")]
+    InvalidSwapProgram,
+    #[msg("Swap delivered less than min_out")]
+    SlippageExceeded,
+
+    #[msg("Update removes recipients with held funds; pass acknowledge_orphaned")]
+    UnacknowledgedOrphanedFunds,
+
+    #[msg("Recipient has not accepted their share (accept_recipient)")]
+    RecipientNotAccepted,
+
+    #[msg("Fee tiers must ascend by min_volume with non-increasing fees of at most 1%")]
+    InvalidFeeTiers,
+
+    #[msg("Authority was active within TAKEOVER_INACTIVITY_SECONDS")]
+    AuthorityStillActive,
+
+    #[msg("Takeover needs signing recipients holding a strict majority of the shares")]
+    InsufficientRecipientWeight,
+
+    #[msg("seed_unclaimed only runs before the config's first execution")]
+    ConfigNotFresh,
+
+    #[msg("Seeded unclaimed entries exceed the vault balance")]
+    SeedExceedsVault,
+
+    #[msg("Recipient token account belongs to a different token program than the mint")]
+    RecipientATAWrongProgram,
+
+    #[msg("Recipient is not the PDA derived from the given program and seeds")]
+    RecipientNotPda,
+
+    #[msg("Pushes to this recipient are paused; claim held funds instead")]
+    RecipientPushPaused,
+
+    #[msg("Destination token account is the config's own vault")]
+    RecipientIsVault,
+
+    #[msg("Weights must pair one-to-one with recipients and total more than zero")]
+    InvalidWeights,
+
+    #[msg("Too soon after the previous execution (min_execute_interval_seconds)")]
+    ExecuteCooldown,
+
+    #[msg("Config has no pending protocol fee to sweep")]
+    NoPendingProtocolFee,
+
+    #[msg("Vault or mint account does not match the config mint")]
+    VaultMintMismatch,
+
+    #[msg("Config keeps an audit log; pass its audit_log account")]
+    AuditLogMissing,
+
+    #[msg("Protocol fee wallet map is full")]
+    TooManyProtocolFeeWallets,
+
+    #[msg("Mint has no protocol fee wallet entry")]
+    ProtocolFeeWalletNotSet,
+
+    #[msg("Config requires claim receipts; claim via claim_unclaimed with a claim_receipt account")]
+    ClaimReceiptMissing,
+
+    #[msg("Target amount exceeds the releasable vault balance")]
+    TargetAboveBalance,
+
+    #[msg("Vault token account is frozen by the mint's freeze authority")]
+    VaultFrozen,
+
+    #[msg("Effective time must be in the future")]
+    InvalidEffectiveTime,
+
+    #[msg("No recipient set is queued")]
+    NoPendingRecipients,
+
+    #[msg("Authority index is full")]
+    AuthorityIndexFull,
+
+    #[msg("At least 2 recipients must keep a share after a recipient leaves")]
+    TooFewRemainingRecipients,
 }
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use cascadepay::cpi::accounts::ExecuteSplit;
+use cascadepay::program::Cascadepay;
+
+declare_id!("5cZKUeVt8pw56rNRFuTpH5M4HH8MWac3PbZUMvWVEECv");
+
+// Example integrator: runs its own logic, then triggers a cascadepay split atomically.
+// The executor is this program's PDA, so cascadepay sees a signer produced by invoke_signed.
+#[program]
+pub mod split_caller {
+    use super::*;
+
+    /// Executes the split via CPI and re-emits cascadepay's return data
+    /// remaining_accounts: forwarded unchanged (recipient ATAs, protocol ATA LAST)
+    pub fn execute_via_cpi<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteViaCpi<'info>>,
+    ) -> Result<()> {
+        let seeds: &[&[u8]] = &[b"executor", &[ctx.bumps.executor]];
+        let signer_seeds = &[seeds];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.cascadepay_program.to_account_info(),
+            ExecuteSplit {
+                split_config: ctx.accounts.split_config.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                executor: ctx.accounts.executor.to_account_info(),
+                admin_config: ctx.accounts.admin_config.to_account_info(),
+                fee_exempt_mints: ctx.accounts.fee_exempt_mints.to_account_info(),
+                protocol_fee_wallets: ctx.accounts.protocol_fee_wallets.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                protocol_ata: None, // Protocol ATA stays LAST in the forwarded remaining_accounts
+                fee_tiers: ctx.accounts.fee_tiers.to_account_info(),
+                audit_log: None, // Configs that keep an AuditLog need it forwarded
+            },
+            signer_seeds,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
+
+        let result = cascadepay::cpi::execute_split(cpi_ctx, false, None, 0, None, None)?.get();
+
+        emit!(SplitTriggered {
+            split_config: ctx.accounts.split_config.key(),
+            distributed: result.distributed,
+            protocol_fee: result.protocol_fee,
+            held: result.held,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ExecuteViaCpi<'info> {
+    /// CHECK: Validated by cascadepay
+    #[account(mut)]
+    pub split_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by cascadepay
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by cascadepay
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: PDA signer for the CPI, holds no data
+    #[account(mut, seeds = [b"executor"], bump)]
+    pub executor: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by cascadepay
+    pub admin_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by cascadepay
+    pub fee_exempt_mints: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by cascadepay
+    pub protocol_fee_wallets: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by cascadepay (the mint's FeeTiers PDA, initialized or not)
+    pub fee_tiers: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by cascadepay
+    pub token_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by cascadepay
+    pub associated_token_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub cascadepay_program: Program<'info, Cascadepay>,
+}
+
+#[event]
+pub struct SplitTriggered {
+    pub split_config: Pubkey,
+    pub distributed: u64,
+    pub protocol_fee: u64,
+    pub held: u64,
+}
@@ -37,6 +37,12 @@ const ASSOCIATED_TOKEN_PROGRAM_ID = new anchor.web3.PublicKey(
   ASSOCIATED_TOKEN_PROGRAM_ADDRESS
 );
 
+// Protocol fee wallet (receives 1% fee and any creation fee) on mainnet builds
+// Devnet builds of the program use their own; pass it to createCascadepayClient
+const PROTOCOL_WALLET = new anchor.web3.PublicKey(
+  "2zMEvEkyQKTRjiGkwYPXjPsJUp8eR1rVjoYQ7PzVVZnP"
+);
+
 // Re-export Anchor types for API compatibility
 type PublicKey = anchor.web3.PublicKey;
 type Keypair = anchor.web3.Keypair;
@@ -49,13 +55,34 @@ export type TransactionInstruction = anchor.web3.TransactionInstruction;
 // Public types - accept dual formats (Web3.js/Anchor AND @solana/kit)
 export interface Recipient {
   address: AddressLike; // Accepts PublicKey, Address, or string
-  percentageBps: number; // 0-9900 (recipients must total 99%)
+  percentageBps: number; // 0-9900 (recipients must total 99%, or 100% on fee-exempt mints)
+  tokenAccount?: AddressLike; // Explicit non-ATA token account (defaults to recipient ATA)
+  subSplit?: AddressLike; // Child split config whose vault receives this share (cascading split)
+  sharePpm?: number; // Parts-per-million share; set on every recipient for ppm precision (total 990000)
+  label?: string; // Off-chain reconciliation id (vendor ID, department code), max 16 UTF-8 bytes
+  frozen?: boolean; // Hold this recipient's share and refuse their claims
+  cliffTs?: number; // Unix time the share starts paying (held or redistributed before it)
 }
 
-// Internal types - used within SDK (always PublicKey)
-interface RecipientInternal {
+// Share precision, fixed at creation (Anchor encoding)
+export type Precision = { bps: Record<string, never> } | { ppm: Record<string, never> };
+
+// On-chain destination enum (Anchor encoding)
+export type RecipientDestination =
+  | { ata: Record<string, never> }
+  | { explicitAccount: { 0: PublicKey } }
+  | { subSplit: { 0: PublicKey } };
+
+// Normalized recipient (always PublicKey) - also the on-chain layout
+export interface RecipientInternal {
   address: PublicKey;
   percentageBps: number;
+  destination: RecipientDestination;
+  label: number[]; // [u8; 16], zero-padded UTF-8
+  frozen: boolean;
+  cliffTs: anchor.BN; // i64 unix seconds, 0 = no cliff
+  accepted: boolean; // Set by acceptRecipient; ignored on create/update
+  pushPaused: boolean; // Share held, claims still allowed
 }
 
 export interface UnclaimedAmount {
@@ -68,15 +95,59 @@ export interface SplitConfig {
   authority: PublicKey;
   mint: PublicKey;
   vault: PublicKey;
-  recipients: Recipient[];
+  recipients: RecipientInternal[];
   unclaimedAmounts: UnclaimedAmount[];
   bump: number;
   version: number;
+  reserve: PublicKey; // Treasury wallet taking reserveBps of each split
+  reserveBps: number; // 0 = no reserve
+  authorities: PublicKey[]; // M-of-N approvers for sensitive ops
+  threshold: number; // 0 = single authority
+  claimDelaySeconds: number; // Grace window before held funds are claimable
+  remainderRecipientIndex: number | null; // Recipient absorbing rounding dust
+  requireAcceptance: boolean; // Shares held until each recipient accepts
+  lastAuthorityActivityTs: anchor.BN; // Starts the recipient takeover window
+  minExecuteIntervalSeconds: number; // Cooldown between distributions, 0 = none
+  lastExecutionTs: anchor.BN; // When the last distribution completed
+  referrer: PublicKey | null; // Partner that referred the config (attribution only)
+  auditLogEnabled: boolean; // Distributions appended to the AuditLog PDA
+  claimReceipts: boolean; // Claims must write a ClaimReceipt PDA
+  claimReceiptNonce: anchor.BN; // Receipts written; seeds the next ClaimReceipt
+  opsAuthority: PublicKey; // Pauses, drains and rescues (defaults to authority)
+  executorTipLamports: anchor.BN; // SOL paid to the executor per distribution
+  executorReserveLamports: anchor.BN; // Prefunded SOL backing the executor tip
+  pendingRecipients: PendingRecipients | null; // Set queued to replace recipients at effectiveTs
+}
+
+export interface PendingRecipients {
+  recipients: RecipientInternal[];
+  sharePpm: number[];
+  effectiveTs: anchor.BN; // First execution at or after this promotes the set
+}
+
+export interface ClaimReceipt {
+  recipient: PublicKey;
+  config: PublicKey;
+  mint: PublicKey;
+  amount: anchor.BN;
+  timestamp: anchor.BN;
+  nonce: anchor.BN;
+}
+
+export interface AuditEntry {
+  timestamp: anchor.BN;
+  totalAmount: anchor.BN; // Balance split this round
+  protocolFee: anchor.BN;
 }
 
 export interface CreateSplitConfigParams {
   mint: AddressLike; // Accepts PublicKey, Address, or string
-  recipients: Recipient[]; // Must sum to 9900 bps (99%)
+  recipients: Recipient[]; // Must sum to 9900 bps (99%), less reserve.bps
+  metadataUri?: string; // Off-chain agreement URI, max 128 bytes
+  allowPermanentDelegate?: boolean; // Accept Token-2022 mints with a permanent delegate (clawback risk)
+  reserve?: { destination: AddressLike; bps: number }; // Treasury cut of each split (max 4900 bps)
+  claimDelaySeconds?: number; // Delay before held funds are claimable (default 0)
+  referrer?: AddressLike; // Referring partner, recorded for attribution
 }
 
 export interface DetectionResult {
@@ -84,6 +155,17 @@ export interface DetectionResult {
   splitConfig?: PublicKey;
 }
 
+/**
+ * Helper: Co-signer keypairs as signing remaining accounts
+ */
+function cosignerMetas(cosigners: Keypair[]) {
+  return cosigners.map((kp) => ({
+    pubkey: kp.publicKey,
+    isSigner: true,
+    isWritable: false,
+  }));
+}
+
 /**
  * Helper: Convert Anchor PublicKey to @solana/kit Address
  */
@@ -98,6 +180,118 @@ function toPublicKey(addr: Address): PublicKey {
   return new anchor.web3.PublicKey(addr);
 }
 
+/**
+ * Helper: Normalize a public Recipient into the on-chain layout
+ */
+function toRecipientInternal(r: Recipient): RecipientInternal {
+  return {
+    address: toPublicKey(r.address),
+    percentageBps: r.percentageBps,
+    destination: r.subSplit
+      ? { subSplit: { 0: toPublicKey(r.subSplit) } }
+      : r.tokenAccount
+        ? { explicitAccount: { 0: toPublicKey(r.tokenAccount) } }
+        : { ata: {} },
+    label: encodeLabel(r.label ?? ""),
+    frozen: r.frozen ?? false,
+    cliffTs: new anchor.BN(r.cliffTs ?? 0),
+    accepted: false,
+    pushPaused: false,
+  };
+}
+
+/**
+ * Helper: Encode a recipient label as zero-padded [u8; 16]
+ */
+function encodeLabel(label: string): number[] {
+  const bytes = new TextEncoder().encode(label);
+  if (bytes.length > 16) {
+    throw new Error(`Recipient label "${label}" exceeds 16 bytes`);
+  }
+  const padded = new Array<number>(16).fill(0);
+  bytes.forEach((b, i) => (padded[i] = b));
+  return padded;
+}
+
+/**
+ * Helper: Resolve precision and ppm shares from recipients, validating the 99% total
+ */
+function toShareArgs(recipients: Recipient[]): {
+  precision: Precision;
+  sharePpm: number[];
+} {
+  if (recipients.every((r) => r.sharePpm === undefined)) {
+    const sum = recipients.reduce((acc, r) => acc + r.percentageBps, 0);
+    if (sum !== 9900) {
+      throw new Error(
+        "Recipient shares must sum to 9900 basis points (99%). Protocol gets 1%."
+      );
+    }
+    return { precision: { bps: {} }, sharePpm: [] };
+  }
+
+  const sharePpm = recipients.map((r) => {
+    if (r.sharePpm === undefined) {
+      throw new Error("Set sharePpm on every recipient or none");
+    }
+    return r.sharePpm;
+  });
+  if (sharePpm.reduce((acc, ppm) => acc + ppm, 0) !== 990_000) {
+    throw new Error("Recipient ppm shares must sum to 990000 (99%)");
+  }
+  return { precision: { ppm: {} }, sharePpm };
+}
+
+/**
+ * Helper: Recipients in the ascending address order the program requires
+ * (remaining_accounts for execute_split follow the same order)
+ */
+function sortRecipients(recipients: Recipient[]): Recipient[] {
+  return [...recipients].sort((a, b) =>
+    Buffer.compare(
+      toPublicKey(a.address).toBuffer(),
+      toPublicKey(b.address).toBuffer()
+    )
+  );
+}
+
+/**
+ * Helper: Token account a recipient is paid into (explicit account, child vault or ATA)
+ */
+async function recipientDestination(
+  recipient: RecipientInternal,
+  mint: PublicKey,
+  tokenProgram: Address
+): Promise<PublicKey> {
+  if ("explicitAccount" in recipient.destination) {
+    return recipient.destination.explicitAccount[0];
+  }
+  const owner =
+    "subSplit" in recipient.destination
+      ? recipient.destination.subSplit[0]
+      : recipient.address;
+  const [ata] = await findAssociatedTokenPda({
+    mint: toAddress(mint),
+    owner: toAddress(owner),
+    tokenProgram,
+  });
+  return toPublicKey(ata);
+}
+
+/**
+ * Helper: Account create/update validates per recipient (child config for sub-splits)
+ */
+async function recipientValidationAccount(
+  recipient: RecipientInternal,
+  mint: PublicKey,
+  tokenProgram: Address
+): Promise<PublicKey> {
+  if ("subSplit" in recipient.destination) {
+    return recipient.destination.subSplit[0];
+  }
+  return recipientDestination(recipient, mint, tokenProgram);
+}
+
 /**
  * Helper function to detect which token program a mint uses
  * @param connection - Solana connection
@@ -120,32 +314,29 @@ async function detectTokenProgram(
 }
 
 export class Cascadepay {
-  constructor(private program: Program, private provider: AnchorProvider) {}
+  constructor(
+    private program: Program,
+    private provider: AnchorProvider,
+    private protocolWallet: PublicKey = PROTOCOL_WALLET
+  ) {}
 
   /**
    * Creates a new split configuration with vault
    * @param params.mint - Token mint address (USDC, USDT, etc.) - accepts PublicKey, Address, or string
-   * @param params.recipients - Array of recipients with percentages (must sum to 9900 bps = 99%)
+   * @param params.recipients - Array of recipients with percentages (must sum to 9900 bps = 99%, or 990000 via sharePpm)
    * @returns PDA address of created split config
    */
   async createSplitConfig(params: CreateSplitConfigParams): Promise<PublicKey> {
     // Normalize inputs at the boundary
     const mint = toPublicKey(params.mint);
-    const recipients: RecipientInternal[] = params.recipients.map((r) => ({
-      address: toPublicKey(r.address),
-      percentageBps: r.percentageBps,
-    }));
+    const sorted = sortRecipients(params.recipients);
+    const recipients: RecipientInternal[] = sorted.map(toRecipientInternal);
 
     // Validate inputs
-    const sum = recipients.reduce((acc, r) => acc + r.percentageBps, 0);
-    if (sum !== 9900) {
-      throw new Error(
-        "Recipient shares must sum to 9900 basis points (99%). Protocol gets 1%."
-      );
-    }
+    const { precision, sharePpm } = toShareArgs(sorted);
 
-    if (recipients.length < 2 || recipients.length > 20) {
-      throw new Error("Must have between 2 and 20 recipients");
+    if (recipients.length < 1 || recipients.length > 20) {
+      throw new Error("Must have between 1 and 20 recipients");
     }
 
     // Derive split config PDA
@@ -172,25 +363,66 @@ export class Cascadepay {
       tokenProgram: tokenProgramAddr,
     });
 
-    // Get recipient ATAs for validation
+    // Get recipient destination accounts for validation
     const recipientAtas = await Promise.all(
       recipients.map((r) =>
-        findAssociatedTokenPda({
-          mint: toAddress(mint),
-          owner: toAddress(r.address),
-          tokenProgram: tokenProgramAddr,
-        }).then(([ata]) => toPublicKey(ata))
+        recipientValidationAccount(r, mint, tokenProgramAddr)
       )
     );
 
+    // Token creation fee (if configured) is paid from the authority's ATA
+    const protocolConfigPDA = this.deriveProtocolConfigPDA();
+    const protocolConfig = (await this.program.account["protocolConfig"].fetch(
+      protocolConfigPDA
+    )) as { creationFeeTokens: anchor.BN };
+    let authorityTokenAccount: PublicKey | null = null;
+    let protocolAta: PublicKey | null = null;
+    if (!protocolConfig.creationFeeTokens.isZero()) {
+      const [authorityAta] = await findAssociatedTokenPda({
+        mint: toAddress(mint),
+        owner: toAddress(this.provider.wallet.publicKey),
+        tokenProgram: tokenProgramAddr,
+      });
+      authorityTokenAccount = toPublicKey(authorityAta);
+      // The creation fee always goes to the default protocol wallet
+      const [creationFeeAta] = await findAssociatedTokenPda({
+        mint: toAddress(mint),
+        owner: toAddress(this.protocolWallet),
+        tokenProgram: tokenProgramAddr,
+      });
+      protocolAta = toPublicKey(creationFeeAta);
+    }
+
     // Create split config
     await this.program.methods
-      .createSplitConfig(mint, recipients)
+      .createSplitConfig(
+        mint,
+        recipients,
+        precision,
+        sharePpm,
+        params.metadataUri ?? "",
+        {
+          allowPermanentDelegate: params.allowPermanentDelegate ?? false,
+          reserve: params.reserve
+            ? {
+                destination: toPublicKey(params.reserve.destination),
+                bps: params.reserve.bps,
+              }
+            : null,
+          claimDelaySeconds: params.claimDelaySeconds ?? 0,
+          referrer: params.referrer ? toPublicKey(params.referrer) : null,
+        }
+      )
       .accounts({
         splitConfig: splitConfigPDA,
         vault: toPublicKey(vaultAta),
         mint,
         authority: this.provider.wallet.publicKey,
+        protocolConfig: protocolConfigPDA,
+        protocolWallet: this.protocolWallet,
+        authorityTokenAccount,
+        protocolAta,
+        authorityIndex: await this.authorityIndexAccount(),
         tokenProgram: tokenProgramPubkey,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -211,9 +443,17 @@ export class Cascadepay {
    * Executes a payment split by draining vault
    * Permissionless - anyone can call
    * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param createAtas - Create missing recipient ATAs (provider wallet pays rent)
+   * @param expectedNonce - Optional execution nonce; a retried transaction fails with StaleNonce
+   * @param targetAmount - Optional exact amount to split (rest stays in the vault); defaults to the full balance
    * @returns Transaction signature
    */
-  async executeSplit(splitConfigPDA: AddressLike): Promise<string> {
+  async executeSplit(
+    splitConfigPDA: AddressLike,
+    createAtas = false,
+    expectedNonce?: number,
+    targetAmount?: bigint
+  ): Promise<string> {
     const pda = toPublicKey(splitConfigPDA);
     const config = await this.getSplitConfig(pda);
 
@@ -223,35 +463,46 @@ export class Cascadepay {
     );
     const tokenProgramPubkey = toPublicKey(tokenProgramAddr);
 
-    // Get recipient ATAs
+    // Get recipient destination accounts
     const recipientAtas = await Promise.all(
       config.recipients.map((r) =>
-        findAssociatedTokenPda({
-          mint: toAddress(config.mint),
-          owner: toAddress(r.address),
-          tokenProgram: tokenProgramAddr,
-        }).then(([ata]) => toPublicKey(ata))
+        recipientDestination(r, config.mint, tokenProgramAddr)
       )
     );
 
-    // Protocol wallet
-    const protocolWallet = new anchor.web3.PublicKey(
-      "2zMEvEkyQKTRjiGkwYPXjPsJUp8eR1rVjoYQ7PzVVZnP"
-    );
     const [protocolAta] = await findAssociatedTokenPda({
       mint: toAddress(config.mint),
-      owner: toAddress(protocolWallet),
+      owner: toAddress(await this.getProtocolFeeWallet(config.mint)),
       tokenProgram: tokenProgramAddr,
     });
 
+    // Recipient wallets follow the ATAs when creating missing ATAs
+    const recipientWallets = createAtas
+      ? config.recipients.map((r) => ({
+          pubkey: r.address,
+          isSigner: false,
+          isWritable: false,
+        }))
+      : [];
+
     const tx = await this.program.methods
-      .executeSplit()
+      .executeSplit(
+        createAtas,
+        null,
+        0,
+        expectedNonce === undefined ? null : new anchor.BN(expectedNonce),
+        targetAmount === undefined
+          ? null
+          : new anchor.BN(targetAmount.toString())
+      )
       .accounts({
         splitConfig: pda,
         vault: config.vault,
         mint: config.mint,
         executor: this.provider.wallet.publicKey,
         tokenProgram: tokenProgramPubkey,
+        protocolAta: toPublicKey(protocolAta),
+        auditLog: this.auditLogAccount(pda, config),
       })
       .remainingAccounts([
         ...recipientAtas.map((ata) => ({
@@ -259,16 +510,118 @@ export class Cascadepay {
           isSigner: false,
           isWritable: true,
         })),
-        // Protocol ATA as LAST remaining account
-        {
-          pubkey: toPublicKey(protocolAta),
+        ...recipientWallets,
+        ...(await this.reserveAccounts(config, tokenProgramAddr)),
+      ])
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Splits the current balance once to an ad-hoc recipient set (authority only)
+   * The stored recipients are unchanged; the set must total like the config's own
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param recipients - One-off recipients with percentages (bps)
+   * @returns Transaction signature
+   */
+  async executeAdhocSplit(
+    splitConfigPDA: AddressLike,
+    recipients: Recipient[]
+  ): Promise<string> {
+    const pda = toPublicKey(splitConfigPDA);
+    const config = await this.getSplitConfig(pda);
+    const adhoc = sortRecipients(recipients).map(toRecipientInternal);
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+    const destinations = await Promise.all(
+      adhoc.map((r) => recipientDestination(r, config.mint, tokenProgramAddr))
+    );
+    const [protocolAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(await this.getProtocolFeeWallet(config.mint)),
+      tokenProgram: tokenProgramAddr,
+    });
+
+    return this.program.methods
+      .executeAdhocSplit(adhoc)
+      .accounts({
+        splitConfig: pda,
+        vault: config.vault,
+        mint: config.mint,
+        executor: this.provider.wallet.publicKey,
+        tokenProgram: toPublicKey(tokenProgramAddr),
+        protocolAta: toPublicKey(protocolAta),
+        auditLog: this.auditLogAccount(pda, config),
+      })
+      .remainingAccounts([
+        ...destinations.map((pubkey) => ({
+          pubkey,
           isSigner: false,
           isWritable: true,
-        },
+        })),
+        ...(await this.reserveAccounts(config, tokenProgramAddr)),
       ])
       .rpc();
+  }
 
-    return tx;
+  /**
+   * Executes up to 8 split configs in one transaction (keeper batching)
+   * Configs failing their checks are skipped and reported in MultiSplitExecuted
+   * All configs must use the same token program
+   * @param splitConfigPDAs - Split configuration addresses - accept PublicKey, Address, or string
+   * @returns Transaction signature
+   */
+  async executeSplitMulti(splitConfigPDAs: AddressLike[]): Promise<string> {
+    const groups: anchor.web3.AccountMeta[][] = [];
+    let tokenProgramAddr: Address | undefined;
+
+    for (const splitConfigPDA of splitConfigPDAs) {
+      const pda = toPublicKey(splitConfigPDA);
+      const config = await this.getSplitConfig(pda);
+      tokenProgramAddr ??= await detectTokenProgram(
+        this.provider.connection,
+        config.mint
+      );
+
+      const recipientAtas = await Promise.all(
+        config.recipients.map((r) =>
+          recipientDestination(r, config.mint, tokenProgramAddr!)
+        )
+      );
+      const protocolAta = await this.getProtocolAta(config.mint);
+
+      const writable = (pubkey: PublicKey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      });
+      groups.push([
+        writable(pda),
+        writable(config.vault),
+        { pubkey: config.mint, isSigner: false, isWritable: false },
+        {
+          pubkey: this.deriveFeeTiersPDA(config.mint),
+          isSigner: false,
+          isWritable: false,
+        },
+        ...recipientAtas.map(writable),
+        ...(await this.reserveAccounts(config, tokenProgramAddr)),
+        writable(protocolAta),
+      ]);
+    }
+
+    return this.program.methods
+      .executeSplitMulti(Buffer.from(groups.map((g) => g.length - 4)))
+      .accounts({
+        executor: this.provider.wallet.publicKey,
+        tokenProgram: toPublicKey(tokenProgramAddr!),
+      })
+      .remainingAccounts(groups.flat())
+      .rpc();
   }
 
   /**
@@ -304,6 +657,11 @@ export class Cascadepay {
         mint: config.mint,
         recipientAta: toPublicKey(recipientAta),
         tokenProgram: tokenProgramPubkey,
+        claimReceipt: this.claimReceiptAccount(
+          splitConfigPDA,
+          config,
+          recipient.publicKey
+        ),
       })
       .signers([recipient])
       .rpc();
@@ -312,105 +670,127 @@ export class Cascadepay {
   }
 
   /**
-   * Fetches split configuration from on-chain PDA
-   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
-   * @returns Split configuration data
+   * Claims held funds for several recipients in one transaction
+   * Every recipient signs for their own entry; one UnclaimedFundsClaimed event each
+   * @param splitConfigPDA - Address of split configuration
+   * @param recipients - Recipient keypairs
+   * @returns Transaction signature
    */
-  async getSplitConfig(splitConfigPDA: AddressLike): Promise<SplitConfig> {
-    const pda = toPublicKey(splitConfigPDA);
-    interface RawSplitConfig {
-      authority: PublicKey;
-      mint: PublicKey;
-      vault: PublicKey;
-      recipients: Recipient[];
-      unclaimedAmounts: UnclaimedAmount[];
-      bump: number;
-      version: number;
-    }
+  async claimUnclaimedMany(
+    splitConfigPDA: PublicKey,
+    recipients: Keypair[]
+  ): Promise<string> {
+    const config = await this.getSplitConfig(splitConfigPDA);
 
-    const splitConfigAccount = this.program.account["splitConfig"];
-    const config = (await splitConfigAccount.fetch(pda)) as RawSplitConfig;
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
 
-    return {
-      authority: config.authority,
-      mint: config.mint,
-      vault: config.vault,
-      recipients: config.recipients,
-      unclaimedAmounts: config.unclaimedAmounts,
-      bump: config.bump,
-      version: config.version,
-    };
+    const remainingAccounts = [];
+    for (const recipient of recipients) {
+      const [recipientAta] = await findAssociatedTokenPda({
+        mint: toAddress(config.mint),
+        owner: toAddress(recipient.publicKey),
+        tokenProgram: tokenProgramAddr,
+      });
+      remainingAccounts.push(
+        { pubkey: recipient.publicKey, isSigner: true, isWritable: false },
+        { pubkey: toPublicKey(recipientAta), isSigner: false, isWritable: true }
+      );
+    }
+
+    return this.program.methods
+      .claimUnclaimedMany()
+      .accounts({
+        splitConfig: splitConfigPDA,
+        vault: config.vault,
+        mint: config.mint,
+        tokenProgram: toPublicKey(tokenProgramAddr),
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers(recipients)
+      .rpc();
   }
 
   /**
-   * Updates split configuration recipients
-   * Requires vault to be empty
-   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
-   * @param newRecipients - New recipients array (must sum to 9900 bps)
+   * Recipients claim held funds and swap them into another mint atomically
+   * The route (program, instruction data, accounts) comes from a DEX quote; the
+   * claim reverts and funds stay held if the output ATA gains less than minOut
+   * @param splitConfigPDA - Address of split configuration
+   * @param recipient - Recipient keypair
+   * @param swap - Swap program, instruction data and route accounts
+   * @param outputMint - Mint the recipient receives - accepts PublicKey, Address, or string
+   * @param minOut - Minimum output amount (slippage guard)
    * @returns Transaction signature
    */
-  async updateSplitConfig(
-    splitConfigPDA: AddressLike,
-    newRecipients: Recipient[]
+  async claimUnclaimedSwap(
+    splitConfigPDA: PublicKey,
+    recipient: Keypair,
+    swap: {
+      program: AddressLike;
+      data: Buffer;
+      accounts: anchor.web3.AccountMeta[];
+    },
+    outputMint: AddressLike,
+    minOut: number | anchor.BN
   ): Promise<string> {
-    // Normalize inputs
-    const pda = toPublicKey(splitConfigPDA);
-    const recipients: RecipientInternal[] = newRecipients.map((r) => ({
-      address: toPublicKey(r.address),
-      percentageBps: r.percentageBps,
-    }));
-
-    const sum = recipients.reduce((acc, r) => acc + r.percentageBps, 0);
-    if (sum !== 9900) {
-      throw new Error("Recipients must sum to 9900 basis points (99%)");
-    }
-
-    const config = await this.getSplitConfig(pda);
+    const config = await this.getSplitConfig(splitConfigPDA);
+    const output = toPublicKey(outputMint);
 
     const tokenProgramAddr = await detectTokenProgram(
       this.provider.connection,
       config.mint
     );
-
-    const recipientAtas = await Promise.all(
-      recipients.map((r) =>
-        findAssociatedTokenPda({
-          mint: toAddress(config.mint),
-          owner: toAddress(r.address),
-          tokenProgram: tokenProgramAddr,
-        }).then(([ata]) => toPublicKey(ata))
-      )
+    const outputTokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      output
     );
 
-    const tx = await this.program.methods
-      .updateSplitConfig(recipients)
+    const [recipientAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(recipient.publicKey),
+      tokenProgram: tokenProgramAddr,
+    });
+    const [recipientOutputAta] = await findAssociatedTokenPda({
+      mint: toAddress(output),
+      owner: toAddress(recipient.publicKey),
+      tokenProgram: outputTokenProgramAddr,
+    });
+
+    return this.program.methods
+      .claimUnclaimedSwap(swap.data, new anchor.BN(minOut))
       .accounts({
-        authority: this.provider.wallet.publicKey,
-        splitConfig: pda,
+        recipient: recipient.publicKey,
+        splitConfig: splitConfigPDA,
         vault: config.vault,
         mint: config.mint,
+        recipientAta: toPublicKey(recipientAta),
+        outputMint: output,
+        recipientOutputAta: toPublicKey(recipientOutputAta),
+        swapProgram: toPublicKey(swap.program),
+        tokenProgram: toPublicKey(tokenProgramAddr),
+        outputTokenProgram: toPublicKey(outputTokenProgramAddr),
       })
-      .remainingAccounts(
-        recipientAtas.map((ata) => ({
-          pubkey: ata,
-          isSigner: false,
-          isWritable: false,
-        }))
-      )
+      .remainingAccounts(swap.accounts)
+      .signers([recipient])
       .rpc();
-
-    return tx;
   }
 
   /**
-   * Closes split configuration and vault
-   * Requires vault empty and no unclaimed funds
-   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * Recipients claim part of their oldest unclaimed entry
+   * The remainder stays held with its original timestamp
+   * @param splitConfigPDA - Address of split configuration
+   * @param recipient - Recipient keypair
+   * @param amount - Amount to claim (at most the held entry)
    * @returns Transaction signature
    */
-  async closeSplitConfig(splitConfigPDA: AddressLike): Promise<string> {
-    const pda = toPublicKey(splitConfigPDA);
-    const config = await this.getSplitConfig(pda);
+  async claimPartial(
+    splitConfigPDA: PublicKey,
+    recipient: Keypair,
+    amount: bigint
+  ): Promise<string> {
+    const config = await this.getSplitConfig(splitConfigPDA);
 
     const tokenProgramAddr = await detectTokenProgram(
       this.provider.connection,
@@ -418,30 +798,908 @@ export class Cascadepay {
     );
     const tokenProgramPubkey = toPublicKey(tokenProgramAddr);
 
+    const [recipientAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(recipient.publicKey),
+      tokenProgram: tokenProgramAddr,
+    });
+
     const tx = await this.program.methods
-      .closeSplitConfig()
+      .claimPartial(new anchor.BN(amount.toString()))
       .accounts({
-        splitConfig: pda,
+        recipient: recipient.publicKey,
+        splitConfig: splitConfigPDA,
         vault: config.vault,
-        authority: this.provider.wallet.publicKey,
+        mint: config.mint,
+        recipientAta: toPublicKey(recipientAta),
         tokenProgram: tokenProgramPubkey,
+        claimReceipt: this.claimReceiptAccount(
+          splitConfigPDA,
+          config,
+          recipient.publicKey
+        ),
       })
+      .signers([recipient])
       .rpc();
 
     return tx;
   }
 
   /**
-   * Helper: Derives split config PDA for authority and mint
-   * @param authority - Authority public key
-   * @param mint - Token mint address
-   * @returns PDA address
+   * Recipient registers (or clears, with null) a delegate allowed to trigger their claims
+   * @param splitConfigPDA - Address of split configuration
+   * @param recipient - Recipient keypair
+   * @param delegate - Delegate address, or null to revoke
+   * @returns Transaction signature
    */
-  deriveSplitConfigPDA(authority: PublicKey, mint: PublicKey): PublicKey {
-    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
-      [Buffer.from("split_config"), authority.toBuffer(), mint.toBuffer()],
-      this.program.programId
-    );
+  async setClaimDelegate(
+    splitConfigPDA: PublicKey,
+    recipient: Keypair,
+    delegate: AddressLike | null
+  ): Promise<string> {
+    return this.program.methods
+      .setClaimDelegate(delegate === null ? null : toPublicKey(delegate))
+      .accounts({
+        recipient: recipient.publicKey,
+        splitConfig: splitConfigPDA,
+      })
+      .signers([recipient])
+      .rpc();
+  }
+
+  /**
+   * Registered delegate claims a recipient's unclaimed funds into the recipient's ATA
+   * @param splitConfigPDA - Address of split configuration
+   * @param delegate - Delegate keypair registered by the recipient
+   * @param recipient - Recipient whose funds are claimed
+   * @returns Transaction signature
+   */
+  async claimUnclaimedDelegated(
+    splitConfigPDA: PublicKey,
+    delegate: Keypair,
+    recipient: AddressLike
+  ): Promise<string> {
+    const config = await this.getSplitConfig(splitConfigPDA);
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+    const tokenProgramPubkey = toPublicKey(tokenProgramAddr);
+
+    const [recipientAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(toPublicKey(recipient)),
+      tokenProgram: tokenProgramAddr,
+    });
+
+    const tx = await this.program.methods
+      .claimUnclaimedDelegated()
+      .accounts({
+        delegate: delegate.publicKey,
+        recipient: toPublicKey(recipient),
+        splitConfig: splitConfigPDA,
+        vault: config.vault,
+        mint: config.mint,
+        recipientAta: toPublicKey(recipientAta),
+        tokenProgram: tokenProgramPubkey,
+      })
+      .signers([delegate])
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Claims a PDA recipient's unclaimed funds into its ATA (anyone can submit)
+   * @param splitConfigPDA - Address of split configuration
+   * @param ownerProgram - Program the recipient PDA is derived from
+   * @param seeds - The PDA's seeds, bump byte included
+   * @returns Transaction signature
+   */
+  async claimUnclaimedPda(
+    splitConfigPDA: PublicKey,
+    ownerProgram: AddressLike,
+    seeds: Uint8Array[]
+  ): Promise<string> {
+    const config = await this.getSplitConfig(splitConfigPDA);
+    const recipient = anchor.web3.PublicKey.createProgramAddressSync(
+      seeds,
+      toPublicKey(ownerProgram)
+    );
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+
+    const [recipientAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(recipient),
+      tokenProgram: tokenProgramAddr,
+    });
+
+    return this.program.methods
+      .claimUnclaimedPda(
+        toPublicKey(ownerProgram),
+        seeds.map((seed) => Buffer.from(seed))
+      )
+      .accounts({
+        recipient,
+        splitConfig: splitConfigPDA,
+        vault: config.vault,
+        mint: config.mint,
+        recipientAta: toPublicKey(recipientAta),
+        tokenProgram: toPublicKey(tokenProgramAddr),
+      })
+      .rpc();
+  }
+
+  /**
+   * Recipients claim unclaimed wSOL as native SOL
+   * Closes the recipient's wSOL ATA, so any existing wSOL there is unwrapped too
+   * @param splitConfigPDA - Address of split configuration (mint must be wSOL)
+   * @param recipient - Recipient keypair
+   * @returns Transaction signature
+   */
+  async claimUnclaimedNative(
+    splitConfigPDA: PublicKey,
+    recipient: Keypair
+  ): Promise<string> {
+    const config = await this.getSplitConfig(splitConfigPDA);
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+    const tokenProgramPubkey = toPublicKey(tokenProgramAddr);
+
+    const [recipientAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(recipient.publicKey),
+      tokenProgram: tokenProgramAddr,
+    });
+
+    const tx = await this.program.methods
+      .claimUnclaimedNative()
+      .accounts({
+        recipient: recipient.publicKey,
+        splitConfig: splitConfigPDA,
+        vault: config.vault,
+        mint: config.mint,
+        recipientAta: toPublicKey(recipientAta),
+        tokenProgram: tokenProgramPubkey,
+      })
+      .signers([recipient])
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Tops up the vault from the wallet's ATA for the config mint (emits VaultFunded)
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param amount - Amount in base units
+   * @returns Transaction signature
+   */
+  async fundVault(
+    splitConfigPDA: AddressLike,
+    amount: bigint
+  ): Promise<string> {
+    const pda = toPublicKey(splitConfigPDA);
+    const config = await this.getSplitConfig(pda);
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+
+    const [funderAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(this.provider.wallet.publicKey),
+      tokenProgram: tokenProgramAddr,
+    });
+
+    return this.program.methods
+      .fundVault(new anchor.BN(amount.toString()))
+      .accounts({
+        funder: this.provider.wallet.publicKey,
+        splitConfig: pda,
+        vault: config.vault,
+        mint: config.mint,
+        funderTokenAccount: toPublicKey(funderAta),
+        tokenProgram: toPublicKey(tokenProgramAddr),
+      })
+      .rpc();
+  }
+
+  /**
+   * Carries owed balances into a fresh config as held entries (migration utility)
+   * Deposits their total from the wallet's ATA; fails after the first execution
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param entries - Recipients and amounts (base units) owed to them
+   * @returns Transaction signature
+   */
+  async seedUnclaimed(
+    splitConfigPDA: AddressLike,
+    entries: { recipient: AddressLike; amount: bigint }[]
+  ): Promise<string> {
+    const pda = toPublicKey(splitConfigPDA);
+    const config = await this.getSplitConfig(pda);
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+
+    const [authorityAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(this.provider.wallet.publicKey),
+      tokenProgram: tokenProgramAddr,
+    });
+
+    return this.program.methods
+      .seedUnclaimed(
+        entries.map((e) => ({
+          recipient: toPublicKey(e.recipient),
+          amount: new anchor.BN(e.amount.toString()),
+        }))
+      )
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: pda,
+        vault: config.vault,
+        mint: config.mint,
+        authorityTokenAccount: toPublicKey(authorityAta),
+        tokenProgram: toPublicKey(tokenProgramAddr),
+      })
+      .rpc();
+  }
+
+  /**
+   * Fetches split configuration from on-chain PDA
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @returns Split configuration data
+   */
+  async getSplitConfig(splitConfigPDA: AddressLike): Promise<SplitConfig> {
+    const pda = toPublicKey(splitConfigPDA);
+    interface RawSplitConfig {
+      authority: PublicKey;
+      mint: PublicKey;
+      vault: PublicKey;
+      recipients: RecipientInternal[];
+      unclaimedAmounts: UnclaimedAmount[];
+      bump: number;
+      version: number;
+      reserve: PublicKey;
+      reserveBps: number;
+      authorities: PublicKey[];
+      threshold: number;
+      claimDelaySeconds: number;
+      remainderRecipientIndex: number | null;
+      requireAcceptance: boolean;
+      lastAuthorityActivityTs: anchor.BN;
+      minExecuteIntervalSeconds: number;
+      lastExecutionTs: anchor.BN;
+      referrer: PublicKey | null;
+      auditLogEnabled: boolean;
+      claimReceipts: boolean;
+      claimReceiptNonce: anchor.BN;
+      opsAuthority: PublicKey;
+      executorTipLamports: anchor.BN;
+      executorReserveLamports: anchor.BN;
+      pendingRecipients: PendingRecipients | null;
+    }
+
+    const splitConfigAccount = this.program.account["splitConfig"];
+    const config = (await splitConfigAccount.fetch(pda)) as RawSplitConfig;
+
+    return {
+      authority: config.authority,
+      mint: config.mint,
+      vault: config.vault,
+      recipients: config.recipients,
+      unclaimedAmounts: config.unclaimedAmounts,
+      bump: config.bump,
+      version: config.version,
+      reserve: config.reserve,
+      reserveBps: config.reserveBps,
+      authorities: config.authorities,
+      threshold: config.threshold,
+      claimDelaySeconds: config.claimDelaySeconds,
+      remainderRecipientIndex: config.remainderRecipientIndex,
+      requireAcceptance: config.requireAcceptance,
+      lastAuthorityActivityTs: config.lastAuthorityActivityTs,
+      minExecuteIntervalSeconds: config.minExecuteIntervalSeconds,
+      lastExecutionTs: config.lastExecutionTs,
+      referrer: config.referrer,
+      auditLogEnabled: config.auditLogEnabled,
+      claimReceipts: config.claimReceipts,
+      claimReceiptNonce: config.claimReceiptNonce,
+      opsAuthority: config.opsAuthority,
+      executorTipLamports: config.executorTipLamports,
+      executorReserveLamports: config.executorReserveLamports,
+      pendingRecipients: config.pendingRecipients,
+    };
+  }
+
+  /**
+   * Updates split configuration recipients
+   * Requires nothing left to split (held entries may remain)
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param newRecipients - New recipients array (must sum to 9900 bps, or 990000 ppm)
+   * @param cosigners - Co-signing authorities (multisig configs only)
+   * @param acknowledgeOrphaned - Allow removing recipients that still have held funds
+   * @returns Transaction signature
+   */
+  async updateSplitConfig(
+    splitConfigPDA: AddressLike,
+    newRecipients: Recipient[],
+    cosigners: Keypair[] = [],
+    acknowledgeOrphaned = false
+  ): Promise<string> {
+    // Normalize inputs
+    const pda = toPublicKey(splitConfigPDA);
+    const sorted = sortRecipients(newRecipients);
+    const recipients: RecipientInternal[] = sorted.map(toRecipientInternal);
+
+    // Precision is fixed at creation; the program rejects a mismatch
+    const { sharePpm } = toShareArgs(sorted);
+
+    const config = await this.getSplitConfig(pda);
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+
+    const recipientAtas = await Promise.all(
+      recipients.map((r) =>
+        recipientValidationAccount(r, config.mint, tokenProgramAddr)
+      )
+    );
+
+    const tx = await this.program.methods
+      .updateSplitConfig(recipients, sharePpm, acknowledgeOrphaned)
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: pda,
+        vault: config.vault,
+        mint: config.mint,
+      })
+      .remainingAccounts([
+        ...recipientAtas.map((ata) => ({
+          pubkey: ata,
+          isSigner: false,
+          isWritable: false,
+        })),
+        ...cosignerMetas(cosigners),
+      ])
+      .signers(cosigners)
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Queues a recipient set to replace the current one at a future time
+   * The first execution at or after effectiveTs pays out the vault at the current
+   * shares, then switches to it; replaces any queued set
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param newRecipients - New recipients array (must sum to 9900 bps, or 990000 ppm)
+   * @param effectiveTs - Unix time the set takes effect (must be in the future)
+   * @param cosigners - Co-signing authorities (multisig configs only)
+   * @returns Transaction signature
+   */
+  async queueRecipients(
+    splitConfigPDA: AddressLike,
+    newRecipients: Recipient[],
+    effectiveTs: number,
+    cosigners: Keypair[] = []
+  ): Promise<string> {
+    const pda = toPublicKey(splitConfigPDA);
+    const sorted = sortRecipients(newRecipients);
+    const recipients: RecipientInternal[] = sorted.map(toRecipientInternal);
+    const { sharePpm } = toShareArgs(sorted);
+
+    const config = await this.getSplitConfig(pda);
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+    const recipientAtas = await Promise.all(
+      recipients.map((r) =>
+        recipientValidationAccount(r, config.mint, tokenProgramAddr)
+      )
+    );
+
+    return this.program.methods
+      .queueRecipients(recipients, sharePpm, new anchor.BN(effectiveTs))
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: pda,
+        vault: config.vault,
+        mint: config.mint,
+      })
+      .remainingAccounts([
+        ...recipientAtas.map((ata) => ({
+          pubkey: ata,
+          isSigner: false,
+          isWritable: false,
+        })),
+        ...cosignerMetas(cosigners),
+      ])
+      .signers(cosigners)
+      .rpc();
+  }
+
+  /**
+   * Drops a queued recipient set before it takes effect
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @returns Transaction signature
+   */
+  async cancelPendingRecipients(splitConfigPDA: AddressLike): Promise<string> {
+    return this.program.methods
+      .cancelPendingRecipients()
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Sets the M-of-N authority set for sensitive ops (empty set and 0 disables)
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param authorities - Approver addresses (max 7, unique)
+   * @param threshold - Approvals required, 1..=authorities.length (0 with an empty set)
+   * @param cosigners - Co-signing authorities when the config is already multisig
+   * @returns Transaction signature
+   */
+  async setMultisig(
+    splitConfigPDA: AddressLike,
+    authorities: AddressLike[],
+    threshold: number,
+    cosigners: Keypair[] = []
+  ): Promise<string> {
+    return this.program.methods
+      .setMultisig(authorities.map(toPublicKey), threshold)
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .remainingAccounts(cosignerMetas(cosigners))
+      .signers(cosigners)
+      .rpc();
+  }
+
+  /**
+   * Sets the grace window between funds being held and becoming claimable
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param claimDelaySeconds - Seconds a held entry must age before claims (0 = instant)
+   * @returns Transaction signature
+   */
+  async setClaimDelay(
+    splitConfigPDA: AddressLike,
+    claimDelaySeconds: number
+  ): Promise<string> {
+    return this.program.methods
+      .setClaimDelay(claimDelaySeconds)
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Opts a config into an on-chain AuditLog of its recent distributions
+   * Only before the first execution; the authority pays the log's rent
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @returns Transaction signature
+   */
+  async initializeAuditLog(splitConfigPDA: AddressLike): Promise<string> {
+    const pda = toPublicKey(splitConfigPDA);
+    return this.program.methods
+      .initializeAuditLog()
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: pda,
+        auditLog: this.deriveAuditLogPDA(pda),
+      })
+      .rpc();
+  }
+
+  /**
+   * Opts the wallet into an on-chain index of its configs (up to 64)
+   * createSplitConfig and closeSplitConfig keep it current from then on
+   * @param existingConfigs - Configs created earlier to backfill - accept PublicKey, Address, or string
+   * @returns Transaction signature
+   */
+  async initializeAuthorityIndex(
+    existingConfigs: AddressLike[] = []
+  ): Promise<string> {
+    return this.program.methods
+      .initializeAuthorityIndex()
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        authorityIndex: this.deriveAuthorityIndexPDA(
+          this.provider.wallet.publicKey
+        ),
+      })
+      .remainingAccounts(
+        existingConfigs.map((config) => ({
+          pubkey: toPublicKey(config),
+          isSigner: false,
+          isWritable: false,
+        }))
+      )
+      .rpc();
+  }
+
+  /**
+   * Lists an authority's configs from its index (no getProgramAccounts scan)
+   * @param authority - Config authority - accepts PublicKey, Address, or string
+   * @returns Indexed config addresses, or null when the authority has no index
+   */
+  async getAuthorityConfigs(
+    authority: AddressLike
+  ): Promise<PublicKey[] | null> {
+    const index = (await this.program.account["authorityIndex"].fetchNullable(
+      this.deriveAuthorityIndexPDA(toPublicKey(authority))
+    )) as { configs: PublicKey[] } | null;
+    return index ? index.configs : null;
+  }
+
+  /**
+   * Reads a config's audit log, oldest distribution first
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @returns Recent distributions (at most 32)
+   */
+  async getAuditLog(splitConfigPDA: AddressLike): Promise<AuditEntry[]> {
+    return (await this.program.methods
+      .getAuditLog()
+      .accounts({
+        auditLog: this.deriveAuditLogPDA(toPublicKey(splitConfigPDA)),
+      })
+      .view()) as AuditEntry[];
+  }
+
+  /**
+   * Hands pause, emergency drain and rescues to a separate operations key
+   * The config authority keeps recipient management and can reassign it
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param opsAuthority - New ops authority - accepts PublicKey, Address, or string
+   * @returns Transaction signature
+   */
+  async setOpsAuthority(
+    splitConfigPDA: AddressLike,
+    opsAuthority: AddressLike
+  ): Promise<string> {
+    return this.program.methods
+      .setOpsAuthority(toPublicKey(opsAuthority))
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Tops up the config's SOL reserve that executor tips are paid from
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param lamports - SOL to add, in lamports (provider wallet pays)
+   * @returns Transaction signature
+   */
+  async fundExecutorReserve(
+    splitConfigPDA: AddressLike,
+    lamports: bigint
+  ): Promise<string> {
+    return this.program.methods
+      .fundExecutorReserve(new anchor.BN(lamports.toString()))
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Sets the SOL tip paid to the executor of each distribution (0 disables it)
+   * Rounds the reserve can't cover run without a tip
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param lamports - Tip per distribution, in lamports
+   * @returns Transaction signature
+   */
+  async setExecutorTip(
+    splitConfigPDA: AddressLike,
+    lamports: bigint
+  ): Promise<string> {
+    return this.program.methods
+      .setExecutorTip(new anchor.BN(lamports.toString()))
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Previews the SOL tip an executor would earn by executing the config now
+   * 0 when the execution would fail or be a no-op, or the reserve can't cover the tip
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param executor - Would-be executor (defaults to the provider wallet)
+   * @returns Tip in lamports
+   */
+  async previewKeeperReward(
+    splitConfigPDA: AddressLike,
+    executor?: AddressLike
+  ): Promise<bigint> {
+    const splitConfig = toPublicKey(splitConfigPDA);
+    const config = await this.program.account.splitConfig.fetch(splitConfig);
+    const lamports = await this.program.methods
+      .previewKeeperReward(
+        executor ? toPublicKey(executor) : this.provider.wallet.publicKey
+      )
+      .accounts({ splitConfig, vault: config.vault })
+      .view();
+    return BigInt(lamports.toString());
+  }
+
+  /**
+   * Requires every claim on the config to write a ClaimReceipt PDA
+   * claimUnclaimed and claimPartial then pass the next receipt automatically
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param enabled - Whether claims must write receipts
+   * @returns Transaction signature
+   */
+  async setClaimReceipts(
+    splitConfigPDA: AddressLike,
+    enabled: boolean
+  ): Promise<string> {
+    return this.program.methods
+      .setClaimReceipts(enabled)
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Lists a recipient's claim receipts across all configs, oldest first
+   * @param recipient - Recipient address - accepts PublicKey, Address, or string
+   * @returns Receipts of the recipient's receipted claims
+   */
+  async getClaimReceipts(recipient: AddressLike): Promise<ClaimReceipt[]> {
+    const receipts = await this.program.account["claimReceipt"].all([
+      { memcmp: { offset: 8, bytes: toPublicKey(recipient).toBase58() } },
+    ]);
+    return receipts
+      .map((receipt) => receipt.account as ClaimReceipt)
+      .sort((a, b) => a.timestamp.cmp(b.timestamp));
+  }
+
+  /**
+   * Names the recipient that receives each round's exact remainder (rounding dust)
+   * Cleared automatically when the recipient set changes
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param index - Recipient index in the (sorted) config, or null for the protocol default
+   * @returns Transaction signature
+   */
+  async setRemainderRecipient(
+    splitConfigPDA: AddressLike,
+    index: number | null
+  ): Promise<string> {
+    return this.program.methods
+      .setRemainderRecipient(index)
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Requires each recipient to accept (acceptRecipient) before receiving funds
+   * Until then their shares are held as unclaimed
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param requireAcceptance - Whether shares wait for recipient acceptance
+   * @returns Transaction signature
+   */
+  async setRequireAcceptance(
+    splitConfigPDA: AddressLike,
+    requireAcceptance: boolean
+  ): Promise<string> {
+    return this.program.methods
+      .setRequireAcceptance(requireAcceptance)
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Recipient signs to accept their share, proving control of their key
+   * Their configured destination token account must already exist
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param recipient - Recipient keypair
+   * @returns Transaction signature
+   */
+  async acceptRecipient(
+    splitConfigPDA: AddressLike,
+    recipient: Keypair
+  ): Promise<string> {
+    const pda = toPublicKey(splitConfigPDA);
+    const config = await this.getSplitConfig(pda);
+    const entry = config.recipients.find((r) =>
+      r.address.equals(recipient.publicKey)
+    );
+    if (!entry) {
+      throw new Error("Signer is not a recipient of this split");
+    }
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+
+    return this.program.methods
+      .acceptRecipient()
+      .accounts({
+        recipient: recipient.publicKey,
+        splitConfig: pda,
+        destination: await recipientDestination(
+          entry,
+          config.mint,
+          tokenProgramAddr
+        ),
+        vault: config.vault,
+        tokenProgram: toPublicKey(tokenProgramAddr),
+      })
+      .signers([recipient])
+      .rpc();
+  }
+
+  /**
+   * Marks the authority as active, restarting the recipient takeover window
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @returns Transaction signature
+   */
+  async authorityHeartbeat(splitConfigPDA: AddressLike): Promise<string> {
+    return this.program.methods
+      .authorityHeartbeat()
+      .accounts({
+        authority: this.provider.wallet.publicKey,
+        splitConfig: toPublicKey(splitConfigPDA),
+      })
+      .rpc();
+  }
+
+  /**
+   * Recipients holding a strict majority of the unfrozen shares restore distribution on a
+   * config whose authority has been inactive for a year (unpause, unfreeze, drop
+   * the executor allowlist and release gate)
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @param recipients - Co-signing recipient keypairs
+   * @returns Transaction signature
+   */
+  async recipientTakeover(
+    splitConfigPDA: AddressLike,
+    recipients: Keypair[]
+  ): Promise<string> {
+    return this.program.methods
+      .recipientTakeover()
+      .accounts({ splitConfig: toPublicKey(splitConfigPDA) })
+      .remainingAccounts(cosignerMetas(recipients))
+      .signers(recipients)
+      .rpc();
+  }
+
+  /**
+   * Closes split configuration and vault
+   * Requires vault empty and no unclaimed funds
+   * @param splitConfigPDA - Address of split configuration - accepts PublicKey, Address, or string
+   * @returns Transaction signature
+   */
+  async closeSplitConfig(splitConfigPDA: AddressLike): Promise<string> {
+    const pda = toPublicKey(splitConfigPDA);
+    const config = await this.getSplitConfig(pda);
+
+    const tokenProgramAddr = await detectTokenProgram(
+      this.provider.connection,
+      config.mint
+    );
+    const tokenProgramPubkey = toPublicKey(tokenProgramAddr);
+
+    const tx = await this.program.methods
+      .closeSplitConfig()
+      .accounts({
+        splitConfig: pda,
+        vault: config.vault,
+        authority: this.provider.wallet.publicKey,
+        authorityIndex: await this.authorityIndexAccount(),
+        tokenProgram: tokenProgramPubkey,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Helper: Derives split config PDA for authority and mint
+   * @param authority - Authority public key
+   * @param mint - Token mint address
+   * @returns PDA address
+   */
+  deriveSplitConfigPDA(authority: PublicKey, mint: PublicKey): PublicKey {
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("split_config"), authority.toBuffer(), mint.toBuffer()],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /**
+   * Helper: Derives an authority's AuthorityIndex PDA
+   * @param authority - Config authority
+   * @returns PDA address
+   */
+  deriveAuthorityIndexPDA(authority: PublicKey): PublicKey {
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("authority_index"), authority.toBuffer()],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /**
+   * Helper: Derives a config's AuditLog PDA
+   * @param splitConfigPDA - Address of split configuration
+   * @returns PDA address
+   */
+  deriveAuditLogPDA(splitConfigPDA: PublicKey): PublicKey {
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("audit_log"), splitConfigPDA.toBuffer()],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /**
+   * Helper: Derives a ClaimReceipt PDA
+   * @param splitConfigPDA - Address of split configuration
+   * @param recipient - Claiming recipient
+   * @param nonce - Config's claimReceiptNonce at the claim
+   * @returns PDA address
+   */
+  deriveClaimReceiptPDA(
+    splitConfigPDA: PublicKey,
+    recipient: PublicKey,
+    nonce: anchor.BN
+  ): PublicKey {
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("claim_receipt"),
+        splitConfigPDA.toBuffer(),
+        recipient.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /**
+   * Helper: Derives the protocol config singleton PDA
+   * @returns PDA address
+   */
+  deriveProtocolConfigPDA(): PublicKey {
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_config")],
+      this.program.programId
+    );
     return pda;
   }
 
@@ -464,7 +1722,7 @@ export class Cascadepay {
    * Facilitators use this to bundle with transfer instruction
    *
    * @param splitConfigPDA - Split configuration address - accepts PublicKey, Address, or string
-   * @param executor - Optional executor (defaults to provider wallet) - accepts PublicKey, Address, or string
+   * @param executor - Optional executor (defaults to provider wallet), must sign the transaction - accepts PublicKey, Address, or string
    * @returns TransactionInstruction ready to add to Transaction
    */
   async buildExecuteSplitInstruction(
@@ -480,24 +1738,16 @@ export class Cascadepay {
     );
     const tokenProgramPubkey = toPublicKey(tokenProgramAddr);
 
-    // Get recipient ATAs
+    // Get recipient destination accounts
     const recipientAtas = await Promise.all(
       config.recipients.map((r) =>
-        findAssociatedTokenPda({
-          mint: toAddress(config.mint),
-          owner: toAddress(r.address),
-          tokenProgram: tokenProgramAddr,
-        }).then(([ata]) => toPublicKey(ata))
+        recipientDestination(r, config.mint, tokenProgramAddr)
       )
     );
 
-    // Protocol wallet
-    const protocolWallet = new anchor.web3.PublicKey(
-      "2zMEvEkyQKTRjiGkwYPXjPsJUp8eR1rVjoYQ7PzVVZnP"
-    );
     const [protocolAta] = await findAssociatedTokenPda({
       mint: toAddress(config.mint),
-      owner: toAddress(protocolWallet),
+      owner: toAddress(await this.getProtocolFeeWallet(config.mint)),
       tokenProgram: tokenProgramAddr,
     });
 
@@ -507,13 +1757,15 @@ export class Cascadepay {
 
     // Use .instruction() instead of .rpc() to get TransactionInstruction
     return await this.program.methods
-      .executeSplit()
+      .executeSplit(false, null, 0, null, null)
       .accounts({
         splitConfig: pda,
         vault: config.vault,
         mint: config.mint,
         executor: executorKey,
         tokenProgram: tokenProgramPubkey,
+        protocolAta: toPublicKey(protocolAta),
+        auditLog: this.auditLogAccount(pda, config),
       })
       .remainingAccounts([
         ...recipientAtas.map((ata) => ({
@@ -521,16 +1773,80 @@ export class Cascadepay {
           isSigner: false,
           isWritable: true,
         })),
-        // Protocol ATA as LAST remaining account
-        {
-          pubkey: toPublicKey(protocolAta),
-          isSigner: false,
-          isWritable: true,
-        },
+        ...(await this.reserveAccounts(config, tokenProgramAddr)),
       ])
       .instruction();
   }
 
+  /**
+   * AuditLog account for execute_split, or null when the config keeps none
+   */
+  private auditLogAccount(
+    splitConfigPDA: PublicKey,
+    config: SplitConfig
+  ): PublicKey | null {
+    return config.auditLogEnabled
+      ? this.deriveAuditLogPDA(splitConfigPDA)
+      : null;
+  }
+
+  /**
+   * Next ClaimReceipt for a claim, or null when the config doesn't require receipts
+   */
+  private claimReceiptAccount(
+    splitConfigPDA: PublicKey,
+    config: SplitConfig,
+    recipient: PublicKey
+  ): PublicKey | null {
+    return config.claimReceipts
+      ? this.deriveClaimReceiptPDA(
+          splitConfigPDA,
+          recipient,
+          config.claimReceiptNonce
+        )
+      : null;
+  }
+
+  /**
+   * Mint's FeeTiers PDA; execute_split_multi takes it in each group (it may be
+   * uninitialized, in which case the full 1% fee applies)
+   */
+  private deriveFeeTiersPDA(mint: PublicKey): PublicKey {
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("fee_tiers"), mint.toBuffer()],
+      this.program.programId
+    );
+    return pda;
+  }
+
+  /**
+   * Wallet's AuthorityIndex, or null when it hasn't opted into one
+   */
+  private async authorityIndexAccount(): Promise<PublicKey | null> {
+    const pda = this.deriveAuthorityIndexPDA(this.provider.wallet.publicKey);
+    const info = await this.provider.connection.getAccountInfo(pda);
+    return info ? pda : null;
+  }
+
+  /**
+   * Reserve ATA remaining-account slot (empty for configs without a reserve)
+   * Sits right before the protocol ATA slot (LAST when protocolAta is passed by name)
+   */
+  private async reserveAccounts(
+    config: SplitConfig,
+    tokenProgramAddr: Address
+  ): Promise<anchor.web3.AccountMeta[]> {
+    if (config.reserveBps === 0) {
+      return [];
+    }
+    const [reserveAta] = await findAssociatedTokenPda({
+      mint: toAddress(config.mint),
+      owner: toAddress(config.reserve),
+      tokenProgram: tokenProgramAddr,
+    });
+    return [{ pubkey: toPublicKey(reserveAta), isSigner: false, isWritable: true }];
+  }
+
   /**
    * Gets the protocol ATA address for a given mint
    * Useful for checking if protocol ATA exists before executing splits
@@ -544,17 +1860,33 @@ export class Cascadepay {
       this.provider.connection,
       mintPubkey
     );
-    const protocolWallet = new anchor.web3.PublicKey(
-      "2zMEvEkyQKTRjiGkwYPXjPsJUp8eR1rVjoYQ7PzVVZnP"
-    );
     const [protocolAta] = await findAssociatedTokenPda({
       mint: toAddress(mintPubkey),
-      owner: toAddress(protocolWallet),
+      owner: toAddress(await this.getProtocolFeeWallet(mintPubkey)),
       tokenProgram: tokenProgramAddr,
     });
     return toPublicKey(protocolAta);
   }
 
+  /**
+   * Wallet whose ATA receives a mint's execution fees: the admin-set entry in
+   * the protocol fee wallet map, else the default protocol wallet
+   * @param mint - The token mint address - accepts PublicKey, Address, or string
+   * @returns Protocol fee wallet for the mint
+   */
+  async getProtocolFeeWallet(mint: AddressLike): Promise<PublicKey> {
+    const mintPubkey = toPublicKey(mint);
+    const [pda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_fee_wallets")],
+      this.program.programId
+    );
+    const map = (await this.program.account["protocolFeeWallets"].fetchNullable(
+      pda
+    )) as { wallets: { mint: PublicKey; wallet: PublicKey }[] } | null;
+    const entry = map?.wallets.find((w) => w.mint.equals(mintPubkey));
+    return entry ? entry.wallet : this.protocolWallet;
+  }
+
   /**
    * Checks if the protocol ATA exists for a given mint
    *
@@ -610,6 +1942,7 @@ export class Cascadepay {
         mint: config.mint,
         recipientAta: toPublicKey(recipientAta),
         tokenProgram: tokenProgramPubkey,
+        claimReceipt: this.claimReceiptAccount(pda, config, recipientPubkey),
       })
       .instruction();
   }
@@ -621,7 +1954,7 @@ export class Cascadepay {
    * @param splitConfigPDA - Split configuration address - accepts PublicKey, Address, or string
    * @param transferAmount - Amount to transfer in token base units (e.g., lamports for SOL, smallest unit for tokens)
    * @param payer - Who's sending the payment - accepts PublicKey, Address, or string
-   * @param executor - Optional executor for the split (defaults to payer) - accepts PublicKey, Address, or string
+   * @param executor - Optional executor for the split (defaults to payer), must sign the transaction - accepts PublicKey, Address, or string
    * @returns Complete Transaction ready to sign and send (atomic execution)
    */
   async buildBundledTransaction(
@@ -792,12 +2125,14 @@ export async function detectSplitVault(
  * @param connection - Solana connection or RPC URL string (e.g., "https://api.mainnet-beta.solana.com")
  * @param wallet - Anchor wallet
  * @param idl - Program IDL (contains program ID in metadata)
+ * @param protocolWallet - Protocol wallet of a `devnet` program build (defaults to mainnet)
  * @returns Cascadepay SDK instance
  */
 export async function createCascadepayClient(
   connection: ConnectionLike,
   wallet: anchor.Wallet,
-  idl: Idl
+  idl: Idl,
+  protocolWallet?: AddressLike
 ): Promise<Cascadepay> {
   const normalizedConnection = toConnection(connection);
 
@@ -806,5 +2141,9 @@ export async function createCascadepayClient(
   });
 
   const program = new Program(idl, provider);
-  return new Cascadepay(program, provider);
+  return new Cascadepay(
+    program,
+    provider,
+    protocolWallet ? toPublicKey(protocolWallet) : PROTOCOL_WALLET
+  );
 }